@@ -20,4 +20,6 @@ fi
 
 cargo clippy --fix --allow-dirty --allow-staged
 cargo "+$NIGHTLY" fmt
-cargo test
\ No newline at end of file
+cargo test
+cargo check --target wasm32-unknown-unknown --lib
+cargo test --all-features
@@ -0,0 +1,128 @@
+//! Opens two windows and closes each one separately. The application exits once every window is
+//! closed.
+
+enum Application {}
+
+struct Uninitialized {
+    titles: Vec<String>,
+}
+
+struct Resumed {
+    windows: winit_ext::WindowSet,
+    titles: winit_ext::WindowDataMap<String>,
+}
+
+struct Suspended {
+    titles: Vec<String>,
+}
+
+struct Exited;
+
+type Error = Box<dyn std::error::Error>;
+
+impl<TUserEvent: 'static> winit_ext::Application<TUserEvent> for Application {
+    type Uninitialized = Uninitialized;
+    type Resumed = Resumed;
+    type Suspended = Suspended;
+    type Exited = Exited;
+    type Error = Error;
+}
+
+impl Resumed {
+    fn create(
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        titles: Vec<String>,
+    ) -> Result<Self, Error> {
+        let mut windows = winit_ext::WindowSet::new();
+        let mut window_titles = winit_ext::WindowDataMap::new();
+        for title in titles {
+            let window = windows.create(
+                event_loop,
+                winit::window::WindowAttributes::default().with_title(title.clone()),
+            )?;
+            window_titles.insert(window.id(), title);
+        }
+        Ok(Self {
+            windows,
+            titles: window_titles,
+        })
+    }
+}
+
+impl<TUserEvent: 'static> winit_ext::ApplicationUninitialized<TUserEvent> for Uninitialized {
+    type Application = Application;
+
+    fn initialize(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<winit_ext::InitialState<Application, TUserEvent>, Error> {
+        Ok(winit_ext::InitialState::Resumed(Resumed::create(
+            event_loop,
+            self.titles,
+        )?))
+    }
+}
+
+impl<TUserEvent: 'static> winit_ext::ApplicationResumed<TUserEvent> for Resumed {
+    type Application = Application;
+
+    fn handle(
+        mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: winit_ext::EventResumed<TUserEvent>,
+    ) -> Result<Self, Error> {
+        // Every window event carries the id of the window it targets, so closing only affects the
+        // window whose close button was pressed. The default `handle` would exit on the first
+        // close request instead.
+        if let winit_ext::EventResumed::WindowEvent {
+            window_id,
+            event: winit::event::WindowEvent::CloseRequested,
+        } = event
+        {
+            // Dropping the last reference to a window closes it.
+            self.windows.remove(window_id);
+            if let Some(title) = self.titles.remove(window_id) {
+                println!("closed {title}");
+            }
+            // Nothing is left to interact with once the last window is gone.
+            if self.windows.is_empty() {
+                event_loop.exit()
+            }
+        }
+        Ok(self)
+    }
+
+    fn suspend(self, _event_loop: &winit::event_loop::ActiveEventLoop) -> Result<Suspended, Error> {
+        // Remember which windows were open so they can be recreated when resumed.
+        let titles = self.titles.into_iter().map(|(_, title)| title).collect();
+        Ok(Suspended { titles })
+    }
+
+    fn exit(self, _event_loop: &winit::event_loop::ActiveEventLoop) -> Result<Exited, Error> {
+        Ok(Exited)
+    }
+}
+
+impl<TUserEvent: 'static> winit_ext::ApplicationSuspended<TUserEvent> for Suspended {
+    type Application = Application;
+
+    fn resume(self, event_loop: &winit::event_loop::ActiveEventLoop) -> Result<Resumed, Error> {
+        Resumed::create(event_loop, self.titles)
+    }
+
+    fn exit(self, _event_loop: &winit::event_loop::ActiveEventLoop) -> Result<Exited, Error> {
+        Ok(Exited)
+    }
+}
+
+fn main() {
+    let event_loop = winit::event_loop::EventLoop::new().expect("event loop error");
+    let Exited = winit_ext::run(
+        event_loop,
+        Uninitialized {
+            titles: vec!["First window".to_string(), "Second window".to_string()],
+        },
+    )
+    .expect("event loop error")
+    .expect("application error");
+}
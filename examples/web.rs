@@ -0,0 +1,27 @@
+//! Build for `wasm32-unknown-unknown` and serve the output with a tool such as `trunk`, which
+//! calls `main` once the module is loaded.
+
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    use winit::platform::web::WindowAttributesExtWebSys;
+
+    let event_loop = winit::event_loop::EventLoop::new().expect("event loop error");
+    winit_ext::run_web(
+        event_loop,
+        winit_ext::ClosureApplication::new(
+            |event_loop| {
+                event_loop
+                    .create_window(winit::window::WindowAttributes::default().with_append(true))
+            },
+            |_window, _event_loop, _event| Ok(()),
+            Ok,
+            |window, _event_loop| Ok(window),
+            |_window| Ok(()),
+        ),
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    eprintln!("this example only runs in a browser, build it for wasm32-unknown-unknown");
+}
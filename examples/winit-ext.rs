@@ -29,13 +29,16 @@ impl<TUserEvent: 'static> winit_ext::Application<TUserEvent> for Application {
 impl<TUserEvent: 'static> winit_ext::ApplicationUninitialized<TUserEvent> for Uninitialized {
     type Application = Application;
 
-    fn initialize(self, event_loop: &winit::event_loop::ActiveEventLoop) -> Result<Resumed, Error> {
+    fn initialize(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<winit_ext::InitialState<Application, TUserEvent>, Error> {
         let Self { window_attributes } = self;
         let window = event_loop.create_window(window_attributes.clone())?;
-        Ok(Resumed {
+        Ok(winit_ext::InitialState::Resumed(Resumed {
             window_attributes,
             window,
-        })
+        }))
     }
 }
 
@@ -0,0 +1,55 @@
+use crate::EventResumed;
+
+/// A sequence of events, for example to replay with [`crate::ApplicationResumed::handle_all`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventBatch<TUserEvent: 'static = ()>(Vec<EventResumed<TUserEvent>>);
+
+impl<TUserEvent: 'static> Default for EventBatch<TUserEvent> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<TUserEvent: 'static> EventBatch<TUserEvent> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a batch with [`EventResumed::NewEvents`], like winit does for every iteration.
+    pub fn new_events(cause: winit::event::StartCause) -> Self {
+        Self(vec![EventResumed::NewEvents(cause)])
+    }
+
+    pub fn push(&mut self, event: EventResumed<TUserEvent>) {
+        self.0.push(event)
+    }
+
+    pub fn push_window_event(
+        &mut self,
+        window_id: winit::window::WindowId,
+        event: winit::event::WindowEvent,
+    ) {
+        self.push(EventResumed::from_window_event(window_id, event))
+    }
+
+    pub fn push_user_event(&mut self, event: TUserEvent) {
+        self.push(EventResumed::UserEvent(event))
+    }
+
+    pub fn push_about_to_wait(&mut self) {
+        self.push(EventResumed::AboutToWait)
+    }
+
+    pub fn events(&self) -> &[EventResumed<TUserEvent>] {
+        &self.0
+    }
+}
+
+impl<TUserEvent: 'static> IntoIterator for EventBatch<TUserEvent> {
+    type Item = EventResumed<TUserEvent>;
+    type IntoIter = std::vec::IntoIter<EventResumed<TUserEvent>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    future::Future,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
+
+use crate::{
+    Application, ApplicationResumed, ApplicationUninitialized, EventResumed, InitialState,
+};
+
+pub trait AsyncApplicationUninitialized<TUserEvent: 'static = ()>: Sized {
+    type Application: Application<TUserEvent, Uninitialized = Blocking<Self>>;
+
+    fn initialize(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> impl Future<
+        Output = Result<
+            InitialState<Self::Application, TUserEvent>,
+            <Self::Application as Application<TUserEvent>>::Error,
+        >,
+    >;
+}
+
+pub trait AsyncApplicationResumed<TUserEvent: 'static = ()>: Sized {
+    type Application: Application<TUserEvent, Resumed = Blocking<Self>>;
+
+    fn handle(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventResumed<TUserEvent>,
+    ) -> impl Future<Output = Result<Self, <Self::Application as Application<TUserEvent>>::Error>>
+    {
+        if let EventResumed::WindowEvent {
+            window_id: _,
+            event: winit::event::WindowEvent::CloseRequested,
+        } = event
+        {
+            event_loop.exit()
+        }
+        std::future::ready(Ok(self))
+    }
+    fn suspend(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> impl Future<
+        Output = Result<
+            <Self::Application as Application<TUserEvent>>::Suspended,
+            <Self::Application as Application<TUserEvent>>::Error,
+        >,
+    >;
+    fn exit(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> impl Future<
+        Output = Result<
+            <Self::Application as Application<TUserEvent>>::Exited,
+            <Self::Application as Application<TUserEvent>>::Error,
+        >,
+    >;
+}
+
+/// Implements the state traits for the async state traits of `T` by running their futures to
+/// completion on the event loop thread.
+///
+/// No events are processed while a future is pending. Futures that need a runtime, such as tokio
+/// I/O, must be polled with that runtime entered, for example through `Handle::enter` before
+/// calling [`crate::run`].
+pub struct Blocking<T>(pub T);
+
+impl<TUserEvent: 'static, T: AsyncApplicationUninitialized<TUserEvent>>
+    ApplicationUninitialized<TUserEvent> for Blocking<T>
+{
+    type Application = T::Application;
+
+    fn initialize(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        InitialState<Self::Application, TUserEvent>,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        block_on(self.0.initialize(event_loop))
+    }
+}
+
+impl<TUserEvent: 'static, T: AsyncApplicationResumed<TUserEvent>> ApplicationResumed<TUserEvent>
+    for Blocking<T>
+{
+    type Application = T::Application;
+
+    fn handle(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventResumed<TUserEvent>,
+    ) -> Result<Self, <Self::Application as Application<TUserEvent>>::Error> {
+        block_on(self.0.handle(event_loop, event)).map(Self)
+    }
+
+    fn suspend(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Suspended,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        block_on(self.0.suspend(event_loop))
+    }
+
+    fn exit(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Exited,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        block_on(self.0.exit(event_loop))
+    }
+}
+
+struct ThreadWaker(std::thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark()
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    Application, ApplicationResumed, ApplicationSuspended, ApplicationUninitialized, EventResumed,
+    InitialState,
+};
+
+type Initialize<TState, TError> =
+    Box<dyn FnOnce(&winit::event_loop::ActiveEventLoop) -> Result<TState, TError>>;
+type Handle<TUserEvent, TState, TError> = Box<
+    dyn FnMut(
+        &mut TState,
+        &winit::event_loop::ActiveEventLoop,
+        EventResumed<TUserEvent>,
+    ) -> Result<(), TError>,
+>;
+type Suspend<TState, TError> = Box<dyn FnMut(TState) -> Result<TState, TError>>;
+type Resume<TState, TError> =
+    Box<dyn FnMut(TState, &winit::event_loop::ActiveEventLoop) -> Result<TState, TError>>;
+type Exit<TState, TError> = Box<dyn FnOnce(TState) -> Result<(), TError>>;
+
+struct Callbacks<TUserEvent: 'static, TState, TError> {
+    handle: Handle<TUserEvent, TState, TError>,
+    suspend: Suspend<TState, TError>,
+    resume: Resume<TState, TError>,
+    exit: Exit<TState, TError>,
+}
+
+/// An application built from closures, for small programs that do not warrant their own state
+/// types. The same `TState` is used while resumed and while suspended.
+pub struct ClosureApplication<TUserEvent: 'static, TState, TError> {
+    initialize: Initialize<TState, TError>,
+    callbacks: Callbacks<TUserEvent, TState, TError>,
+}
+
+pub struct ClosureResumed<TUserEvent: 'static, TState, TError> {
+    state: TState,
+    callbacks: Callbacks<TUserEvent, TState, TError>,
+}
+
+pub struct ClosureSuspended<TUserEvent: 'static, TState, TError> {
+    state: TState,
+    callbacks: Callbacks<TUserEvent, TState, TError>,
+}
+
+impl<TUserEvent: 'static, TState, TError> ClosureApplication<TUserEvent, TState, TError> {
+    pub fn new(
+        initialize: impl FnOnce(&winit::event_loop::ActiveEventLoop) -> Result<TState, TError> + 'static,
+        handle: impl FnMut(
+                &mut TState,
+                &winit::event_loop::ActiveEventLoop,
+                EventResumed<TUserEvent>,
+            ) -> Result<(), TError>
+            + 'static,
+        suspend: impl FnMut(TState) -> Result<TState, TError> + 'static,
+        resume: impl FnMut(TState, &winit::event_loop::ActiveEventLoop) -> Result<TState, TError>
+            + 'static,
+        exit: impl FnOnce(TState) -> Result<(), TError> + 'static,
+    ) -> Self {
+        Self {
+            initialize: Box::new(initialize),
+            callbacks: Callbacks {
+                handle: Box::new(handle),
+                suspend: Box::new(suspend),
+                resume: Box::new(resume),
+                exit: Box::new(exit),
+            },
+        }
+    }
+}
+
+impl<TUserEvent: 'static, TState, TError> Application<TUserEvent>
+    for ClosureApplication<TUserEvent, TState, TError>
+{
+    type Uninitialized = Self;
+    type Resumed = ClosureResumed<TUserEvent, TState, TError>;
+    type Suspended = ClosureSuspended<TUserEvent, TState, TError>;
+    type Exited = ();
+    type Error = TError;
+}
+
+impl<TUserEvent: 'static, TState, TError> ApplicationUninitialized<TUserEvent>
+    for ClosureApplication<TUserEvent, TState, TError>
+{
+    type Application = Self;
+
+    fn initialize(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<InitialState<Self, TUserEvent>, TError> {
+        let Self {
+            initialize,
+            callbacks,
+        } = self;
+        let state = initialize(event_loop)?;
+        Ok(InitialState::Resumed(ClosureResumed { state, callbacks }))
+    }
+}
+
+impl<TUserEvent: 'static, TState, TError> ApplicationResumed<TUserEvent>
+    for ClosureResumed<TUserEvent, TState, TError>
+{
+    type Application = ClosureApplication<TUserEvent, TState, TError>;
+
+    fn handle(
+        mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventResumed<TUserEvent>,
+    ) -> Result<Self, TError> {
+        (self.callbacks.handle)(&mut self.state, event_loop, event)?;
+        Ok(self)
+    }
+
+    fn suspend(
+        self,
+        _event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<ClosureSuspended<TUserEvent, TState, TError>, TError> {
+        let Self {
+            state,
+            mut callbacks,
+        } = self;
+        let state = (callbacks.suspend)(state)?;
+        Ok(ClosureSuspended { state, callbacks })
+    }
+
+    fn exit(self, _event_loop: &winit::event_loop::ActiveEventLoop) -> Result<(), TError> {
+        (self.callbacks.exit)(self.state)
+    }
+}
+
+impl<TUserEvent: 'static, TState, TError> ApplicationSuspended<TUserEvent>
+    for ClosureSuspended<TUserEvent, TState, TError>
+{
+    type Application = ClosureApplication<TUserEvent, TState, TError>;
+
+    fn resume(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<ClosureResumed<TUserEvent, TState, TError>, TError> {
+        let Self {
+            state,
+            mut callbacks,
+        } = self;
+        let state = (callbacks.resume)(state, event_loop)?;
+        Ok(ClosureResumed { state, callbacks })
+    }
+
+    fn exit(self, _event_loop: &winit::event_loop::ActiveEventLoop) -> Result<(), TError> {
+        (self.callbacks.exit)(self.state)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    Application, ApplicationResumed, ApplicationSuspended, ApplicationUninitialized, EventResumed,
+    EventSuspended, InitialState,
+};
+
+/// Runs two applications side by side, for example an application and a debug overlay.
+///
+/// Every event is cloned and handled by `A` first and then by `B`. Transitions are applied to `A`
+/// first and then to `B`. The composition fails as soon as either application returns an error,
+/// which is why both must use the same error type. When one application initializes into the
+/// resumed state and the other into the suspended state, the resumed one is suspended right away
+/// so that both resume together.
+pub struct CompositeApplication<A, B>(A, B);
+
+impl<A, B> CompositeApplication<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self(a, b)
+    }
+
+    pub fn into_parts(self) -> (A, B) {
+        (self.0, self.1)
+    }
+}
+
+impl<
+        TUserEvent: Clone + 'static,
+        A: Application<TUserEvent>,
+        B: Application<TUserEvent, Error = A::Error>,
+    > Application<TUserEvent> for CompositeApplication<A, B>
+{
+    type Uninitialized = CompositeApplication<A::Uninitialized, B::Uninitialized>;
+    type Resumed = CompositeApplication<A::Resumed, B::Resumed>;
+    type Suspended = CompositeApplication<A::Suspended, B::Suspended>;
+    type Exited = (A::Exited, B::Exited);
+    type Error = A::Error;
+}
+
+impl<
+        TUserEvent: Clone + 'static,
+        A: ApplicationUninitialized<TUserEvent>,
+        B: ApplicationUninitialized<TUserEvent>,
+    > ApplicationUninitialized<TUserEvent> for CompositeApplication<A, B>
+where
+    B::Application:
+        Application<TUserEvent, Error = <A::Application as Application<TUserEvent>>::Error>,
+{
+    type Application = CompositeApplication<A::Application, B::Application>;
+
+    fn initialize(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        InitialState<Self::Application, TUserEvent>,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        let a = self.0.initialize(event_loop)?;
+        let b = self.1.initialize(event_loop)?;
+        Ok(match (a, b) {
+            (InitialState::Resumed(a), InitialState::Resumed(b)) => {
+                InitialState::Resumed(CompositeApplication(a, b))
+            }
+            (InitialState::Resumed(a), InitialState::Suspended(b)) => {
+                InitialState::Suspended(CompositeApplication(a.suspend(event_loop)?, b))
+            }
+            (InitialState::Suspended(a), InitialState::Resumed(b)) => {
+                InitialState::Suspended(CompositeApplication(a, b.suspend(event_loop)?))
+            }
+            (InitialState::Suspended(a), InitialState::Suspended(b)) => {
+                InitialState::Suspended(CompositeApplication(a, b))
+            }
+        })
+    }
+}
+
+impl<
+        TUserEvent: Clone + 'static,
+        A: ApplicationResumed<TUserEvent>,
+        B: ApplicationResumed<TUserEvent>,
+    > ApplicationResumed<TUserEvent> for CompositeApplication<A, B>
+where
+    B::Application:
+        Application<TUserEvent, Error = <A::Application as Application<TUserEvent>>::Error>,
+{
+    type Application = CompositeApplication<A::Application, B::Application>;
+
+    fn handle(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventResumed<TUserEvent>,
+    ) -> Result<Self, <Self::Application as Application<TUserEvent>>::Error> {
+        let a = self.0.handle(event_loop, event.clone())?;
+        let b = self.1.handle(event_loop, event)?;
+        Ok(CompositeApplication(a, b))
+    }
+
+    fn suspend(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Suspended,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        let a = self.0.suspend(event_loop)?;
+        let b = self.1.suspend(event_loop)?;
+        Ok(CompositeApplication(a, b))
+    }
+
+    fn exit(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Exited,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        let a = self.0.exit(event_loop)?;
+        let b = self.1.exit(event_loop)?;
+        Ok((a, b))
+    }
+}
+
+impl<
+        TUserEvent: Clone + 'static,
+        A: ApplicationSuspended<TUserEvent>,
+        B: ApplicationSuspended<TUserEvent>,
+    > ApplicationSuspended<TUserEvent> for CompositeApplication<A, B>
+where
+    B::Application:
+        Application<TUserEvent, Error = <A::Application as Application<TUserEvent>>::Error>,
+{
+    type Application = CompositeApplication<A::Application, B::Application>;
+
+    fn handle(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventSuspended<TUserEvent>,
+    ) -> Result<Self, <Self::Application as Application<TUserEvent>>::Error> {
+        let a = self.0.handle(event_loop, event.clone())?;
+        let b = self.1.handle(event_loop, event)?;
+        Ok(CompositeApplication(a, b))
+    }
+
+    fn resume(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Resumed,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        let a = self.0.resume(event_loop)?;
+        let b = self.1.resume(event_loop)?;
+        Ok(CompositeApplication(a, b))
+    }
+
+    fn exit(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Exited,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        let a = self.0.exit(event_loop)?;
+        let b = self.1.exit(event_loop)?;
+        Ok((a, b))
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{
+    Application, ApplicationResumed, ApplicationStateKind, ApplicationSuspended,
+    ApplicationUninitialized, EventResumed, EventSuspended, InitialState,
+};
+
+/// An application error together with the state that produced it and the event being handled.
+#[derive(Debug)]
+pub struct TransitionError<E> {
+    pub state: ApplicationStateKind,
+    /// The name of the event being handled, like `WindowEvent`. Only present when the error was
+    /// returned from `handle`.
+    pub event_description: Option<String>,
+    pub source: E,
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for TransitionError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.event_description {
+            Some(event_description) => write!(
+                f,
+                "error in {:?} state during {}: {}",
+                self.state, event_description, self.source
+            ),
+            None => write!(f, "error in {:?} state: {}", self.state, self.source),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for TransitionError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Wraps an application and attaches context to its errors in the form of a [`TransitionError`].
+pub struct ErrorContext<T>(T);
+
+impl<T> ErrorContext<T> {
+    pub fn wrap(inner: T) -> Self {
+        Self(inner)
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<TUserEvent: 'static, TApplication: Application<TUserEvent>> Application<TUserEvent>
+    for ErrorContext<TApplication>
+{
+    type Uninitialized = ErrorContext<TApplication::Uninitialized>;
+    type Resumed = ErrorContext<TApplication::Resumed>;
+    type Suspended = ErrorContext<TApplication::Suspended>;
+    type Exited = TApplication::Exited;
+    type Error = TransitionError<TApplication::Error>;
+}
+
+impl<TUserEvent: 'static, T: ApplicationUninitialized<TUserEvent>>
+    ApplicationUninitialized<TUserEvent> for ErrorContext<T>
+{
+    type Application = ErrorContext<T::Application>;
+
+    fn initialize(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        InitialState<Self::Application, TUserEvent>,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        Ok(
+            match self
+                .0
+                .initialize(event_loop)
+                .map_err(with_state(ApplicationStateKind::Uninitialized))?
+            {
+                InitialState::Resumed(state) => InitialState::Resumed(ErrorContext(state)),
+                InitialState::Suspended(state) => InitialState::Suspended(ErrorContext(state)),
+            },
+        )
+    }
+}
+
+impl<TUserEvent: 'static, T: ApplicationResumed<TUserEvent>> ApplicationResumed<TUserEvent>
+    for ErrorContext<T>
+{
+    type Application = ErrorContext<T::Application>;
+
+    fn handle(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventResumed<TUserEvent>,
+    ) -> Result<Self, <Self::Application as Application<TUserEvent>>::Error> {
+        let event_name = event.name();
+        self.0
+            .handle(event_loop, event)
+            .map(ErrorContext)
+            .map_err(|source| TransitionError {
+                state: ApplicationStateKind::Resumed,
+                event_description: Some(event_name.to_owned()),
+                source,
+            })
+    }
+
+    fn suspend(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Suspended,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        self.0
+            .suspend(event_loop)
+            .map(ErrorContext)
+            .map_err(with_state(ApplicationStateKind::Resumed))
+    }
+
+    fn exit(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Exited,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        self.0
+            .exit(event_loop)
+            .map_err(with_state(ApplicationStateKind::Resumed))
+    }
+}
+
+impl<TUserEvent: 'static, T: ApplicationSuspended<TUserEvent>> ApplicationSuspended<TUserEvent>
+    for ErrorContext<T>
+{
+    type Application = ErrorContext<T::Application>;
+
+    fn handle(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventSuspended<TUserEvent>,
+    ) -> Result<Self, <Self::Application as Application<TUserEvent>>::Error> {
+        let event_name = event.name();
+        self.0
+            .handle(event_loop, event)
+            .map(ErrorContext)
+            .map_err(|source| TransitionError {
+                state: ApplicationStateKind::Suspended,
+                event_description: Some(event_name.to_owned()),
+                source,
+            })
+    }
+
+    fn resume(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Resumed,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        self.0
+            .resume(event_loop)
+            .map(ErrorContext)
+            .map_err(with_state(ApplicationStateKind::Suspended))
+    }
+
+    fn exit(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Exited,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        self.0
+            .exit(event_loop)
+            .map_err(with_state(ApplicationStateKind::Suspended))
+    }
+}
+
+fn with_state<E>(state: ApplicationStateKind) -> impl FnOnce(E) -> TransitionError<E> {
+    move |source| TransitionError {
+        state,
+        event_description: None,
+        source,
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use crate::EventResumed;
+
+/// Whether a window should be closed after handling an event.
+pub type ShouldClose = bool;
+
+/// Handles the events of a single window registered with a [`WindowEventDispatcher`].
+pub trait WindowHandler<TUserEvent: 'static = ()> {
+    type Error;
+
+    fn handle(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: &winit::event::WindowEvent,
+    ) -> Result<ShouldClose, Self::Error>;
+}
+
+/// Routes window events to the state of the window they target.
+#[derive(Debug)]
+pub struct WindowEventDispatcher<S> {
+    windows: HashMap<winit::window::WindowId, S>,
+}
+
+impl<S> Default for WindowEventDispatcher<S> {
+    fn default() -> Self {
+        Self {
+            windows: HashMap::new(),
+        }
+    }
+}
+
+impl<S> WindowEventDispatcher<S> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, id: winit::window::WindowId, state: S) {
+        self.windows.insert(id, state);
+    }
+
+    pub fn unregister(&mut self, id: winit::window::WindowId) {
+        self.windows.remove(&id);
+    }
+
+    /// Calls `handler` with the state of the window the event targets. Events that do not target
+    /// a registered window are ignored.
+    pub fn dispatch<TUserEvent: 'static, E>(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventResumed<TUserEvent>,
+        mut handler: impl FnMut(
+            &mut S,
+            &winit::event_loop::ActiveEventLoop,
+            &winit::event::WindowEvent,
+        ) -> Result<(), E>,
+    ) -> Result<(), E> {
+        match event.into_window_event() {
+            Some((window_id, event)) => match self.windows.get_mut(&window_id) {
+                Some(state) => handler(state, event_loop, &event),
+                None => Ok(()),
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+impl<H> WindowEventDispatcher<H> {
+    /// Like [`Self::dispatch`] but calls [`WindowHandler::handle`] and unregisters the window when
+    /// it should close.
+    pub fn dispatch_handler<TUserEvent: 'static>(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventResumed<TUserEvent>,
+    ) -> Result<(), H::Error>
+    where
+        H: WindowHandler<TUserEvent>,
+    {
+        if let Some((window_id, event)) = event.into_window_event() {
+            if let Some(handler) = self.windows.get_mut(&window_id) {
+                if handler.handle(event_loop, &event)? {
+                    self.windows.remove(&window_id);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{DragDropEvent, EventResumed};
+
+/// Borrows the payload of an [`EventResumed`].
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum EventResumedRef<'a, T: 'static> {
+    NewEvents(winit::event::StartCause),
+    WindowEvent {
+        window_id: winit::window::WindowId,
+        event: &'a winit::event::WindowEvent,
+    },
+    DeviceEvent {
+        device_id: winit::event::DeviceId,
+        event: &'a winit::event::DeviceEvent,
+    },
+    DragDrop {
+        window_id: winit::window::WindowId,
+        event: &'a DragDropEvent,
+    },
+    ThemeChanged {
+        window_id: winit::window::WindowId,
+        theme: winit::window::Theme,
+    },
+    Ime {
+        window_id: winit::window::WindowId,
+        event: &'a winit::event::Ime,
+    },
+    ScaleFactorChanged {
+        window_id: winit::window::WindowId,
+        scale_factor: f64,
+        inner_size_writer: &'a winit::event::InnerSizeWriter,
+    },
+    UserEvent(&'a T),
+    AboutToWait,
+    MemoryWarning,
+}
+
+impl<T: 'static> Clone for EventResumedRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for EventResumedRef<'_, T> {}
+
+impl<T: 'static> EventResumed<T> {
+    pub fn as_ref(&self) -> EventResumedRef<'_, T> {
+        match self {
+            Self::NewEvents(cause) => EventResumedRef::NewEvents(*cause),
+            Self::WindowEvent { window_id, event } => EventResumedRef::WindowEvent {
+                window_id: *window_id,
+                event,
+            },
+            Self::DeviceEvent { device_id, event } => EventResumedRef::DeviceEvent {
+                device_id: *device_id,
+                event,
+            },
+            Self::DragDrop { window_id, event } => EventResumedRef::DragDrop {
+                window_id: *window_id,
+                event,
+            },
+            Self::ThemeChanged { window_id, theme } => EventResumedRef::ThemeChanged {
+                window_id: *window_id,
+                theme: *theme,
+            },
+            Self::Ime { window_id, event } => EventResumedRef::Ime {
+                window_id: *window_id,
+                event,
+            },
+            Self::ScaleFactorChanged {
+                window_id,
+                scale_factor,
+                inner_size_writer,
+            } => EventResumedRef::ScaleFactorChanged {
+                window_id: *window_id,
+                scale_factor: *scale_factor,
+                inner_size_writer,
+            },
+            Self::UserEvent(event) => EventResumedRef::UserEvent(event),
+            Self::AboutToWait => EventResumedRef::AboutToWait,
+            Self::MemoryWarning => EventResumedRef::MemoryWarning,
+        }
+    }
+}
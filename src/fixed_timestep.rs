@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+/// Turns variable frame durations into a number of fixed simulation steps. Time that does not add
+/// up to a whole step is carried over to the next update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedTimestep {
+    accumulator: Duration,
+    pub step: Duration,
+}
+
+impl FixedTimestep {
+    pub fn new(step: Duration) -> Self {
+        Self {
+            accumulator: Duration::ZERO,
+            step,
+        }
+    }
+
+    /// Adds the frame duration and returns how many steps to simulate. Always returns zero when the
+    /// step is zero. The count saturates at `u32::MAX`, dropping any steps beyond it.
+    pub fn update(&mut self, dt: impl Into<Duration>) -> u32 {
+        if self.step.is_zero() {
+            return 0;
+        }
+        let accumulated = self.accumulator.saturating_add(dt.into()).as_nanos();
+        let step = self.step.as_nanos();
+        // The remainder is smaller than the step, so it fits in a `Duration`.
+        let remainder = accumulated % step;
+        self.accumulator = Duration::new(
+            (remainder / 1_000_000_000) as u64,
+            (remainder % 1_000_000_000) as u32,
+        );
+        u32::try_from(accumulated / step).unwrap_or(u32::MAX)
+    }
+
+    /// Returns how far the carried over time is into the next step, between `0.0` and `1.0`, for
+    /// interpolating between the last two simulated states.
+    pub fn alpha(&self) -> f64 {
+        if self.step.is_zero() {
+            return 0.0;
+        }
+        self.accumulator.as_secs_f64() / self.step.as_secs_f64()
+    }
+}
@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+
+use crate::EventResumed;
+
+/// Tracks which windows have input focus. Windows that have not received a
+/// [`winit::event::WindowEvent::Focused`] event yet are considered unfocused.
+#[derive(Debug, Clone, Default)]
+pub struct FocusTracker(HashMap<winit::window::WindowId, bool>);
+
+impl FocusTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update<TUserEvent>(&mut self, event: &EventResumed<TUserEvent>) {
+        match event {
+            EventResumed::WindowEvent {
+                window_id,
+                event: winit::event::WindowEvent::Focused(focused),
+            } => {
+                self.0.insert(*window_id, *focused);
+            }
+            EventResumed::WindowEvent {
+                window_id,
+                event: winit::event::WindowEvent::Destroyed,
+            } => {
+                self.0.remove(window_id);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn is_focused(&self, id: winit::window::WindowId) -> bool {
+        self.0.get(&id).copied().unwrap_or(false)
+    }
+}
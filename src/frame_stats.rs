@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+/// Summarizes frame durations, for example the ones returned by [`crate::FrameTimer::tick`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameStats {
+    min_dt: Duration,
+    max_dt: Duration,
+    mean_dt: Duration,
+    sample_count: u64,
+    /// The sum of squared differences from the mean in seconds squared, see Welford's algorithm.
+    m2: f64,
+}
+
+impl Default for FrameStats {
+    fn default() -> Self {
+        Self {
+            min_dt: Duration::MAX,
+            max_dt: Duration::ZERO,
+            mean_dt: Duration::ZERO,
+            sample_count: 0,
+            m2: 0.0,
+        }
+    }
+}
+
+impl FrameStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, dt: impl Into<Duration>) {
+        let dt = dt.into();
+        self.sample_count += 1;
+        self.min_dt = self.min_dt.min(dt);
+        self.max_dt = self.max_dt.max(dt);
+        let mean = self.mean_dt.as_secs_f64();
+        let delta = dt.as_secs_f64() - mean;
+        let mean = mean + delta / self.sample_count as f64;
+        self.m2 += delta * (dt.as_secs_f64() - mean);
+        self.mean_dt = Duration::from_secs_f64(mean);
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns `None` until the first update.
+    pub fn min_dt(&self) -> Option<Duration> {
+        (self.sample_count > 0).then_some(self.min_dt)
+    }
+
+    /// Returns `None` until the first update.
+    pub fn max_dt(&self) -> Option<Duration> {
+        (self.sample_count > 0).then_some(self.max_dt)
+    }
+
+    /// Returns `None` until the first update.
+    pub fn mean_dt(&self) -> Option<Duration> {
+        (self.sample_count > 0).then_some(self.mean_dt)
+    }
+
+    pub fn sample_count(&self) -> u64 {
+        self.sample_count
+    }
+
+    /// Returns the population standard deviation of the frame durations in milliseconds.
+    pub fn stddev_ms(&self) -> f64 {
+        if self.sample_count == 0 {
+            return 0.0;
+        }
+        (self.m2 / self.sample_count as f64).sqrt() * 1000.0
+    }
+
+    /// Formats the statistics on a single line, for logging.
+    pub fn report(&self) -> String {
+        if self.sample_count == 0 {
+            return "no frames".to_string();
+        }
+        format!(
+            "{} frames, min {:.2} ms, mean {:.2} ms, max {:.2} ms, stddev {:.2} ms",
+            self.sample_count,
+            self.min_dt.as_secs_f64() * 1000.0,
+            self.mean_dt.as_secs_f64() * 1000.0,
+            self.max_dt.as_secs_f64() * 1000.0,
+            self.stddev_ms(),
+        )
+    }
+}
@@ -0,0 +1,58 @@
+use std::{collections::VecDeque, time::Duration};
+
+use crate::{DeltaTime, Instant};
+
+/// The number of frames [`FrameTimer::fps_rolling`] averages over.
+const ROLLING_FRAMES: usize = 60;
+
+/// Measures the time between frames. Call [`Self::tick`] once per frame, for example from
+/// [`crate::ApplicationResumed::on_about_to_wait`].
+#[derive(Debug, Clone)]
+pub struct FrameTimer {
+    last_frame: Instant,
+    frame_count: u64,
+    recent: VecDeque<Duration>,
+}
+
+impl Default for FrameTimer {
+    fn default() -> Self {
+        Self {
+            last_frame: Instant::now(),
+            frame_count: 0,
+            recent: VecDeque::with_capacity(ROLLING_FRAMES),
+        }
+    }
+}
+
+impl FrameTimer {
+    /// Starts measuring the first frame now.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ends the current frame and returns its duration.
+    pub fn tick(&mut self) -> DeltaTime {
+        let now = Instant::now();
+        let dt = now - self.last_frame;
+        self.last_frame = now;
+        self.frame_count += 1;
+        if self.recent.len() == ROLLING_FRAMES {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(dt);
+        DeltaTime(dt)
+    }
+
+    /// Returns the frame rate averaged over the last 60 frames, or `0.0` before the first tick.
+    pub fn fps_rolling(&self) -> f64 {
+        let total = self.recent.iter().sum::<Duration>().as_secs_f64();
+        if total == 0.0 {
+            return 0.0;
+        }
+        self.recent.len() as f64 / total
+    }
+
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+}
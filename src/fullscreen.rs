@@ -0,0 +1,63 @@
+/// The display mode of a window managed by [`FullscreenManager`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum FullscreenMode {
+    #[default]
+    Windowed,
+    Borderless,
+    Exclusive(winit::monitor::VideoModeHandle),
+}
+
+/// Switches a window in and out of fullscreen and restores its position and size when it returns
+/// to windowed mode.
+#[derive(Debug, Clone, Default)]
+pub struct FullscreenManager {
+    current: FullscreenMode,
+    saved_position: Option<winit::dpi::PhysicalPosition<i32>>,
+    saved_size: Option<winit::dpi::PhysicalSize<u32>>,
+}
+
+impl FullscreenManager {
+    /// Assumes the window starts out windowed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn current(&self) -> &FullscreenMode {
+        &self.current
+    }
+
+    /// Enters borderless fullscreen on the current monitor when windowed and returns to windowed
+    /// mode otherwise.
+    pub fn toggle(&mut self, window: &winit::window::Window) {
+        let mode = match self.current {
+            FullscreenMode::Windowed => FullscreenMode::Borderless,
+            _ => FullscreenMode::Windowed,
+        };
+        self.set_mode(window, mode)
+    }
+
+    pub fn set_mode(&mut self, window: &winit::window::Window, mode: FullscreenMode) {
+        if self.current == FullscreenMode::Windowed && mode != FullscreenMode::Windowed {
+            self.saved_position = window.outer_position().ok();
+            self.saved_size = Some(window.inner_size());
+        }
+        match &mode {
+            FullscreenMode::Windowed => {
+                window.set_fullscreen(None);
+                if let Some(size) = self.saved_size.take() {
+                    let _ = window.request_inner_size(size);
+                }
+                if let Some(position) = self.saved_position.take() {
+                    window.set_outer_position(position);
+                }
+            }
+            FullscreenMode::Borderless => {
+                window.set_fullscreen(Some(winit::window::Fullscreen::Borderless(None)))
+            }
+            FullscreenMode::Exclusive(video_mode) => window.set_fullscreen(Some(
+                winit::window::Fullscreen::Exclusive(video_mode.clone()),
+            )),
+        }
+        self.current = mode;
+    }
+}
@@ -0,0 +1,129 @@
+use winit::application::ApplicationHandler;
+
+use crate::{Adapter, Application, ApplicationStateKind, Instant};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransitionRecord {
+    Initialize,
+    Resume,
+    Suspend,
+    Exit,
+    /// A state method returned an error while the application was in the given state.
+    Error(ApplicationStateKind),
+    /// An event was delivered to the application, after the event filter and resize coalescing.
+    Event(String),
+}
+
+/// Wraps an [`Adapter`] and records every state transition and event with the time it happened.
+pub struct StateTransitionHistory<TApplication: Application<TUserEvent>, TUserEvent: 'static> {
+    adapter: Adapter<TApplication, TUserEvent>,
+    records: Vec<(Instant, TransitionRecord)>,
+}
+
+impl<TApplication: Application<TUserEvent>, TUserEvent: 'static>
+    StateTransitionHistory<TApplication, TUserEvent>
+{
+    pub fn new(mut adapter: Adapter<TApplication, TUserEvent>) -> Self {
+        adapter.event_log = Some(Vec::new());
+        Self {
+            adapter,
+            records: Vec::new(),
+        }
+    }
+
+    pub fn records(&self) -> &[(Instant, TransitionRecord)] {
+        &self.records
+    }
+
+    pub fn into_records(self) -> Vec<(Instant, TransitionRecord)> {
+        self.records
+    }
+
+    pub fn into_parts(
+        mut self,
+    ) -> (
+        Adapter<TApplication, TUserEvent>,
+        Vec<(Instant, TransitionRecord)>,
+    ) {
+        self.adapter.event_log = None;
+        (self.adapter, self.records)
+    }
+
+    fn record<F: FnOnce(&mut Adapter<TApplication, TUserEvent>)>(&mut self, f: F) {
+        let from = self.adapter.state_kind();
+        f(&mut self.adapter);
+        if let Some(event_log) = &mut self.adapter.event_log {
+            self.records.extend(
+                event_log
+                    .drain(..)
+                    .map(|(time, event)| (time, TransitionRecord::Event(event))),
+            );
+        }
+        let to = self.adapter.state_kind();
+        let record = match (from, to) {
+            (ApplicationStateKind::Error, _) => return,
+            (from, ApplicationStateKind::Error) => TransitionRecord::Error(from),
+            (from, to) if from == to => return,
+            (ApplicationStateKind::Uninitialized, _) => TransitionRecord::Initialize,
+            (_, ApplicationStateKind::Resumed) => TransitionRecord::Resume,
+            (_, ApplicationStateKind::Suspended) => TransitionRecord::Suspend,
+            (_, ApplicationStateKind::Exited) => TransitionRecord::Exit,
+            (_, ApplicationStateKind::Uninitialized) => return,
+        };
+        self.records.push((Instant::now(), record));
+    }
+}
+
+impl<TApplication: Application<TUserEvent>, TUserEvent: 'static> ApplicationHandler<TUserEvent>
+    for StateTransitionHistory<TApplication, TUserEvent>
+{
+    fn new_events(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        cause: winit::event::StartCause,
+    ) {
+        self.record(|adapter| adapter.new_events(event_loop, cause))
+    }
+
+    fn user_event(&mut self, event_loop: &winit::event_loop::ActiveEventLoop, event: TUserEvent) {
+        self.record(|adapter| adapter.user_event(event_loop, event))
+    }
+
+    fn device_event(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        device_id: winit::event::DeviceId,
+        event: winit::event::DeviceEvent,
+    ) {
+        self.record(|adapter| adapter.device_event(event_loop, device_id, event))
+    }
+
+    fn about_to_wait(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.record(|adapter| adapter.about_to_wait(event_loop))
+    }
+
+    fn suspended(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.record(|adapter| adapter.suspended(event_loop))
+    }
+
+    fn exiting(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.record(|adapter| adapter.exiting(event_loop))
+    }
+
+    fn memory_warning(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.record(|adapter| adapter.memory_warning(event_loop))
+    }
+
+    fn resumed(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.record(|adapter| adapter.resumed(event_loop))
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        window_id: winit::window::WindowId,
+        event: winit::event::WindowEvent,
+    ) {
+        self.record(|adapter| adapter.window_event(event_loop, window_id, event))
+    }
+}
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+use crate::EventResumed;
+
+/// Tracks which physical keys are held down so they can be polled every frame.
+///
+/// Synthetic key events are ignored. All keys are considered released when a window loses focus
+/// because the release events are not delivered to unfocused windows.
+#[derive(Debug, Clone, Default)]
+pub struct KeyboardStateTracker(HashMap<winit::keyboard::PhysicalKey, winit::event::ElementState>);
+
+impl KeyboardStateTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update<TUserEvent>(&mut self, event: &EventResumed<TUserEvent>) {
+        let EventResumed::WindowEvent { event, .. } = event else {
+            return;
+        };
+        match event {
+            winit::event::WindowEvent::KeyboardInput {
+                event,
+                is_synthetic: false,
+                ..
+            } => {
+                self.0.insert(event.physical_key, event.state);
+            }
+            winit::event::WindowEvent::Focused(false) => self.0.clear(),
+            _ => {}
+        }
+    }
+
+    pub fn is_pressed(&self, key: winit::keyboard::PhysicalKey) -> bool {
+        self.0.get(&key).is_some_and(|state| state.is_pressed())
+    }
+}
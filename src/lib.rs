@@ -1,15 +1,172 @@
+mod batch;
+#[cfg(not(target_arch = "wasm32"))]
+mod blocking;
+mod closure;
+mod composite;
+mod context;
+mod dispatcher;
+#[cfg(feature = "event-ref")]
+mod event_ref;
+mod fixed_timestep;
+mod focus;
+mod frame_stats;
+mod frame_timer;
+mod fullscreen;
+mod history;
+mod keyboard;
+mod macros;
+mod modifiers;
+mod monitor;
+mod mouse;
+mod observer;
+#[cfg(feature = "serde")]
+mod persist;
+mod plugin;
+mod queue;
+mod runner;
+mod scroll;
+mod sender;
+#[cfg(all(unix, feature = "signals"))]
+mod signals;
+mod snapshot;
 mod takeable;
+mod time;
+mod touch;
+mod tracer;
+#[cfg(not(target_arch = "wasm32"))]
+mod watchdog;
+mod window_data;
+mod window_geometry;
+mod window_set;
 
-use crate::takeable::Takeable;
+// winit uses `web_time::Instant` in `ControlFlow::WaitUntil` on the web, where `std::time::Instant`
+// is not available.
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+/// Derives [`Application`](trait@Application) for a type that names the state types.
+///
+/// ```
+/// use std::convert::Infallible;
+///
+/// use winit::event_loop::ActiveEventLoop;
+/// use winit_ext::{
+///     ApplicationResumed, ApplicationSuspended, ApplicationUninitialized, InitialState,
+/// };
+///
+/// #[derive(winit_ext::Application)]
+/// #[application(
+///     uninitialized = Uninitialized,
+///     resumed = Resumed,
+///     suspended = Suspended,
+///     exited = (),
+///     error = Infallible,
+///     user_event = (),
+/// )]
+/// enum MyApp {}
+///
+/// struct Uninitialized;
+/// struct Resumed;
+/// struct Suspended;
+///
+/// impl ApplicationUninitialized for Uninitialized {
+///     type Application = MyApp;
+///
+///     fn initialize(self, _: &ActiveEventLoop) -> Result<InitialState<MyApp>, Infallible> {
+///         Ok(InitialState::Suspended(Suspended))
+///     }
+/// }
+///
+/// impl ApplicationResumed for Resumed {
+///     type Application = MyApp;
+///
+///     fn suspend(self, _: &ActiveEventLoop) -> Result<Suspended, Infallible> {
+///         Ok(Suspended)
+///     }
+///
+///     fn exit(self, _: &ActiveEventLoop) -> Result<(), Infallible> {
+///         Ok(())
+///     }
+/// }
+///
+/// impl ApplicationSuspended for Suspended {
+///     type Application = MyApp;
+///
+///     fn resume(self, _: &ActiveEventLoop) -> Result<Resumed, Infallible> {
+///         Ok(Resumed)
+///     }
+///
+///     fn exit(self, _: &ActiveEventLoop) -> Result<(), Infallible> {
+///         Ok(())
+///     }
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use winit_ext_derive::Application;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::blocking::{AsyncApplicationResumed, AsyncApplicationUninitialized, Blocking};
+#[cfg(feature = "event-ref")]
+pub use crate::event_ref::EventResumedRef;
+#[cfg(feature = "serde")]
+pub use crate::persist::{export_resumed_state, restore_resumed_state, RestoreError};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::watchdog::ShutdownWatchdog;
+pub use crate::{
+    batch::EventBatch,
+    closure::{ClosureApplication, ClosureResumed, ClosureSuspended},
+    composite::CompositeApplication,
+    context::{ErrorContext, TransitionError},
+    dispatcher::{ShouldClose, WindowEventDispatcher, WindowHandler},
+    fixed_timestep::FixedTimestep,
+    focus::FocusTracker,
+    frame_stats::FrameStats,
+    frame_timer::FrameTimer,
+    fullscreen::{FullscreenManager, FullscreenMode},
+    history::{StateTransitionHistory, TransitionRecord},
+    keyboard::KeyboardStateTracker,
+    modifiers::ModifierKeyTracker,
+    monitor::MonitorHandleExt,
+    mouse::MouseButtonTracker,
+    observer::ApplicationLifecycleObserver,
+    plugin::ApplicationPlugin,
+    queue::BoundedEventQueue,
+    runner::ApplicationRunnerBuilder,
+    scroll::ScrollAccumulator,
+    sender::{AppEventSender, EventLoopProxyExt},
+    snapshot::{SnapshotAdapter, StateSnapshot},
+    time::{AbsoluteTime, DeltaTime},
+    touch::TouchStateTracker,
+    tracer::ApplicationTracer,
+    window_data::WindowDataMap,
+    window_geometry::WindowGeometry,
+    window_set::WindowSet,
+};
+use crate::{runner::Options, takeable::Takeable};
 
 pub trait Application<TUserEvent: 'static = ()>: Sized {
     type Uninitialized: ApplicationUninitialized<TUserEvent, Application = Self>;
     type Resumed: ApplicationResumed<TUserEvent, Application = Self>;
     type Suspended: ApplicationSuspended<TUserEvent, Application = Self>;
     type Exited;
+    /// Returning an error from any state method exits the event loop and the error is returned
+    /// from [`run`].
+    ///
+    /// State methods take the application by value, so there is no state left to continue with
+    /// after an error. Errors that should not terminate the application must be handled inside
+    /// the state method.
     type Error;
 }
 
+/// Maps the exited state to a process exit code for [`run_with_exit_code`].
+pub trait HasExitCode<TUserEvent: 'static = ()>: Application<TUserEvent> {
+    fn exit_code(_exited: &Self::Exited) -> i32 {
+        0
+    }
+}
+
 pub trait ApplicationUninitialized<TUserEvent: 'static = ()>: Sized {
     type Application: Application<TUserEvent, Uninitialized = Self>;
 
@@ -17,28 +174,109 @@ pub trait ApplicationUninitialized<TUserEvent: 'static = ()>: Sized {
         self,
         event_loop: &winit::event_loop::ActiveEventLoop,
     ) -> Result<
-        <Self::Application as Application<TUserEvent>>::Resumed,
+        InitialState<Self::Application, TUserEvent>,
         <Self::Application as Application<TUserEvent>>::Error,
     >;
 }
 
+pub enum InitialState<TApplication: Application<TUserEvent>, TUserEvent: 'static = ()> {
+    Resumed(TApplication::Resumed),
+    Suspended(TApplication::Suspended),
+}
+
 pub trait ApplicationResumed<TUserEvent: 'static = ()>: Sized {
     type Application: Application<TUserEvent, Resumed = Self>;
 
     fn handle(
-        self,
+        mut self,
         event_loop: &winit::event_loop::ActiveEventLoop,
         event: EventResumed<TUserEvent>,
     ) -> Result<Self, <Self::Application as Application<TUserEvent>>::Error> {
-        if let EventResumed::WindowEvent {
-            window_id: _,
-            event: winit::event::WindowEvent::CloseRequested,
-        } = event
-        {
-            event_loop.exit()
+        match event {
+            EventResumed::WindowEvent {
+                window_id,
+                event: winit::event::WindowEvent::CloseRequested,
+            } => self.on_close_requested(event_loop, window_id),
+            EventResumed::WindowEvent {
+                window_id,
+                event: winit::event::WindowEvent::Resized(new_size),
+            } => self.on_resized(event_loop, window_id, new_size),
+            EventResumed::WindowEvent {
+                window_id,
+                event:
+                    winit::event::WindowEvent::KeyboardInput {
+                        event,
+                        is_synthetic,
+                        ..
+                    },
+            } => self.on_keyboard_input(event_loop, window_id, &event, is_synthetic),
+            EventResumed::ScaleFactorChanged {
+                window_id,
+                scale_factor,
+                ..
+            } => self.on_scale_factor_changed(event_loop, window_id, scale_factor),
+            EventResumed::AboutToWait => self.on_about_to_wait(event_loop)?,
+            _ => {}
         }
         Ok(self)
     }
+    /// Called by the default [`Self::handle`] when a window asks to be closed. Exits by default.
+    fn on_close_requested(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        _window_id: winit::window::WindowId,
+    ) {
+        event_loop.exit()
+    }
+    /// Called by the default [`Self::handle`] when a window is resized. Does nothing by default.
+    fn on_resized(
+        &mut self,
+        _event_loop: &winit::event_loop::ActiveEventLoop,
+        _window_id: winit::window::WindowId,
+        _new_size: winit::dpi::PhysicalSize<u32>,
+    ) {
+    }
+    /// Called by the default [`Self::handle`] when the scale factor of a window changes. Does
+    /// nothing by default.
+    fn on_scale_factor_changed(
+        &mut self,
+        _event_loop: &winit::event_loop::ActiveEventLoop,
+        _window_id: winit::window::WindowId,
+        _scale_factor: f64,
+    ) {
+    }
+    /// Called by the default [`Self::handle`] for keyboard input to a window. Does nothing by
+    /// default.
+    fn on_keyboard_input(
+        &mut self,
+        _event_loop: &winit::event_loop::ActiveEventLoop,
+        _window_id: winit::window::WindowId,
+        _event: &winit::event::KeyEvent,
+        _is_synthetic: bool,
+    ) {
+    }
+    /// Called by the default [`Self::handle`] when the event loop is about to wait for new events,
+    /// which is where most applications render. Does nothing by default.
+    fn on_about_to_wait(
+        &mut self,
+        _event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<(), <Self::Application as Application<TUserEvent>>::Error> {
+        Ok(())
+    }
+    /// Handles the events in order and stops at the first error.
+    fn handle_all(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        events: impl IntoIterator<Item = EventResumed<TUserEvent>>,
+    ) -> Result<Self, <Self::Application as Application<TUserEvent>>::Error> {
+        events
+            .into_iter()
+            .try_fold(self, |state, event| state.handle(event_loop, event))
+    }
+    /// Asks the event loop to exit, which eventually calls [`Self::exit`].
+    fn request_exit(&self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        event_loop.exit()
+    }
     fn suspend(
         self,
         event_loop: &winit::event_loop::ActiveEventLoop,
@@ -63,9 +301,19 @@ pub trait ApplicationSuspended<TUserEvent: 'static = ()>: Sized {
         event_loop: &winit::event_loop::ActiveEventLoop,
         event: EventSuspended<TUserEvent>,
     ) -> Result<Self, <Self::Application as Application<TUserEvent>>::Error> {
-        let _ = (event_loop, event);
+        if let EventSuspended::WindowEvent {
+            window_id: _,
+            event: winit::event::WindowEvent::CloseRequested,
+        } = event
+        {
+            event_loop.exit()
+        }
         Ok(self)
     }
+    /// Asks the event loop to exit, which eventually calls [`Self::exit`].
+    fn request_exit(&self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        event_loop.exit()
+    }
     fn resume(
         self,
         event_loop: &winit::event_loop::ActiveEventLoop,
@@ -82,9 +330,22 @@ pub trait ApplicationSuspended<TUserEvent: 'static = ()>: Sized {
     >;
 }
 
+/// The file drag-and-drop events of a window. winit reports every file separately, so dragging
+/// several files produces one event per file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DragDropEvent {
+    Hovered(std::path::PathBuf),
+    Cancelled,
+    Dropped(std::path::PathBuf),
+}
+
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum EventResumed<T: 'static> {
     NewEvents(winit::event::StartCause),
+    /// A window event without a dedicated variant. File drag-and-drop, theme, input method and
+    /// scale factor events are delivered as [`Self::DragDrop`], [`Self::ThemeChanged`],
+    /// [`Self::Ime`] and [`Self::ScaleFactorChanged`] instead.
     WindowEvent {
         window_id: winit::window::WindowId,
         event: winit::event::WindowEvent,
@@ -93,34 +354,524 @@ pub enum EventResumed<T: 'static> {
         device_id: winit::event::DeviceId,
         event: winit::event::DeviceEvent,
     },
+    DragDrop {
+        window_id: winit::window::WindowId,
+        event: DragDropEvent,
+    },
+    ThemeChanged {
+        window_id: winit::window::WindowId,
+        theme: winit::window::Theme,
+    },
+    Ime {
+        window_id: winit::window::WindowId,
+        event: winit::event::Ime,
+    },
+    /// The scale factor of a window changed. winit does not report the new inner size, but the
+    /// writer can be used to request one.
+    ScaleFactorChanged {
+        window_id: winit::window::WindowId,
+        scale_factor: f64,
+        inner_size_writer: winit::event::InnerSizeWriter,
+    },
     UserEvent(T),
     AboutToWait,
     MemoryWarning,
 }
 
+impl<T: 'static> EventResumed<T> {
+    /// The name of the variant, for describing the event without formatting its contents.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Self::NewEvents(_) => "NewEvents",
+            Self::WindowEvent { .. } => "WindowEvent",
+            Self::DeviceEvent { .. } => "DeviceEvent",
+            Self::DragDrop { .. } => "DragDrop",
+            Self::ThemeChanged { .. } => "ThemeChanged",
+            Self::Ime { .. } => "Ime",
+            Self::ScaleFactorChanged { .. } => "ScaleFactorChanged",
+            Self::UserEvent(_) => "UserEvent",
+            Self::AboutToWait => "AboutToWait",
+            Self::MemoryWarning => "MemoryWarning",
+        }
+    }
+
+    /// Wraps a window event, routing the events that have a dedicated variant to that variant.
+    pub fn from_window_event(
+        window_id: winit::window::WindowId,
+        event: winit::event::WindowEvent,
+    ) -> Self {
+        match event {
+            winit::event::WindowEvent::HoveredFile(path) => Self::DragDrop {
+                window_id,
+                event: DragDropEvent::Hovered(path),
+            },
+            winit::event::WindowEvent::HoveredFileCancelled => Self::DragDrop {
+                window_id,
+                event: DragDropEvent::Cancelled,
+            },
+            winit::event::WindowEvent::DroppedFile(path) => Self::DragDrop {
+                window_id,
+                event: DragDropEvent::Dropped(path),
+            },
+            winit::event::WindowEvent::ThemeChanged(theme) => {
+                Self::ThemeChanged { window_id, theme }
+            }
+            winit::event::WindowEvent::Ime(event) => Self::Ime { window_id, event },
+            winit::event::WindowEvent::ScaleFactorChanged {
+                scale_factor,
+                inner_size_writer,
+            } => Self::ScaleFactorChanged {
+                window_id,
+                scale_factor,
+                inner_size_writer,
+            },
+            event => Self::WindowEvent { window_id, event },
+        }
+    }
+
+    /// Returns the id of the window that the event targets, including events with a dedicated
+    /// variant such as [`Self::DragDrop`].
+    pub fn window_id(&self) -> Option<winit::window::WindowId> {
+        match self {
+            Self::WindowEvent { window_id, .. }
+            | Self::DragDrop { window_id, .. }
+            | Self::ThemeChanged { window_id, .. }
+            | Self::Ime { window_id, .. }
+            | Self::ScaleFactorChanged { window_id, .. } => Some(*window_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the event if it is a [`Self::WindowEvent`] that targets the window with the given
+    /// id. Events with a dedicated variant such as [`Self::DragDrop`] are not included because
+    /// they do not hold a [`winit::event::WindowEvent`], use [`Self::for_window`] for those.
+    pub fn window_event(&self, id: winit::window::WindowId) -> Option<&winit::event::WindowEvent> {
+        match self {
+            Self::WindowEvent { window_id, event } if *window_id == id => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Returns the window event if it targets the window with the given id. Events with a
+    /// dedicated variant are turned back into the window event winit delivered.
+    pub fn for_window(self, id: winit::window::WindowId) -> Option<winit::event::WindowEvent> {
+        match self.into_window_event()? {
+            (window_id, event) if window_id == id => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn is_close_requested(&self, id: winit::window::WindowId) -> bool {
+        matches!(
+            self.window_event(id),
+            Some(winit::event::WindowEvent::CloseRequested)
+        )
+    }
+
+    pub fn is_redraw_requested(&self, id: winit::window::WindowId) -> bool {
+        matches!(
+            self.window_event(id),
+            Some(winit::event::WindowEvent::RedrawRequested)
+        )
+    }
+
+    /// Returns the new inner size if the given window was resized.
+    pub fn window_resized(
+        &self,
+        id: winit::window::WindowId,
+    ) -> Option<winit::dpi::PhysicalSize<u32>> {
+        match self.window_event(id) {
+            Some(winit::event::WindowEvent::Resized(size)) => Some(*size),
+            _ => None,
+        }
+    }
+
+    /// Returns the key event and whether it is synthetic if the given window received keyboard
+    /// input. Most applications ignore synthetic events, which winit generates for keys that were
+    /// already held when the window gained focus.
+    pub fn keyboard_input(
+        &self,
+        id: winit::window::WindowId,
+    ) -> Option<(&winit::event::KeyEvent, bool)> {
+        match self.window_event(id) {
+            Some(winit::event::WindowEvent::KeyboardInput {
+                device_id: _,
+                event,
+                is_synthetic,
+            }) => Some((event, *is_synthetic)),
+            _ => None,
+        }
+    }
+
+    /// Returns the window event and the window it targets. Events with a dedicated variant are
+    /// turned back into the window event winit delivered, the inverse of
+    /// [`Self::from_window_event`].
+    pub fn into_window_event(self) -> Option<(winit::window::WindowId, winit::event::WindowEvent)> {
+        let (window_id, event) = match self {
+            Self::WindowEvent { window_id, event } => (window_id, event),
+            Self::DragDrop { window_id, event } => (
+                window_id,
+                match event {
+                    DragDropEvent::Hovered(path) => winit::event::WindowEvent::HoveredFile(path),
+                    DragDropEvent::Cancelled => winit::event::WindowEvent::HoveredFileCancelled,
+                    DragDropEvent::Dropped(path) => winit::event::WindowEvent::DroppedFile(path),
+                },
+            ),
+            Self::ThemeChanged { window_id, theme } => {
+                (window_id, winit::event::WindowEvent::ThemeChanged(theme))
+            }
+            Self::Ime { window_id, event } => (window_id, winit::event::WindowEvent::Ime(event)),
+            Self::ScaleFactorChanged {
+                window_id,
+                scale_factor,
+                inner_size_writer,
+            } => (
+                window_id,
+                winit::event::WindowEvent::ScaleFactorChanged {
+                    scale_factor,
+                    inner_size_writer,
+                },
+            ),
+            _ => return None,
+        };
+        Some((window_id, event))
+    }
+
+    pub fn device_event_ref(&self) -> Option<(winit::event::DeviceId, &winit::event::DeviceEvent)> {
+        match self {
+            Self::DeviceEvent { device_id, event } => Some((*device_id, event)),
+            _ => None,
+        }
+    }
+
+    pub fn into_device_event(self) -> Option<(winit::event::DeviceId, winit::event::DeviceEvent)> {
+        match self {
+            Self::DeviceEvent { device_id, event } => Some((device_id, event)),
+            _ => None,
+        }
+    }
+
+    pub fn user_event_ref(&self) -> Option<&T> {
+        match self {
+            Self::UserEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn into_user_event(self) -> Option<T> {
+        match self {
+            Self::UserEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Returns the user event, or the event itself if it is not a user event.
+    #[allow(clippy::result_large_err)]
+    pub fn take_user_event(self) -> Result<T, Self> {
+        match self {
+            Self::UserEvent(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+
+    pub fn is_new_events(&self) -> bool {
+        matches!(self, Self::NewEvents(_))
+    }
+
+    pub fn start_cause(&self) -> Option<&winit::event::StartCause> {
+        match self {
+            Self::NewEvents(cause) => Some(cause),
+            _ => None,
+        }
+    }
+
+    pub fn is_about_to_wait(&self) -> bool {
+        matches!(self, Self::AboutToWait)
+    }
+
+    pub fn is_memory_warning(&self) -> bool {
+        matches!(self, Self::MemoryWarning)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum EventSuspended<T: 'static> {
     NewEvents(winit::event::StartCause),
+    /// A window event without a dedicated variant. File drag-and-drop, theme, input method and
+    /// scale factor events are delivered as [`Self::DragDrop`], [`Self::ThemeChanged`],
+    /// [`Self::Ime`] and [`Self::ScaleFactorChanged`] instead.
+    WindowEvent {
+        window_id: winit::window::WindowId,
+        event: winit::event::WindowEvent,
+    },
     DeviceEvent {
         device_id: winit::event::DeviceId,
         event: winit::event::DeviceEvent,
     },
+    DragDrop {
+        window_id: winit::window::WindowId,
+        event: DragDropEvent,
+    },
+    ThemeChanged {
+        window_id: winit::window::WindowId,
+        theme: winit::window::Theme,
+    },
+    Ime {
+        window_id: winit::window::WindowId,
+        event: winit::event::Ime,
+    },
+    /// The scale factor of a window changed. winit does not report the new inner size, but the
+    /// writer can be used to request one.
+    ScaleFactorChanged {
+        window_id: winit::window::WindowId,
+        scale_factor: f64,
+        inner_size_writer: winit::event::InnerSizeWriter,
+    },
     UserEvent(T),
     AboutToWait,
     MemoryWarning,
 }
 
-struct Adapter<TApplication: Application<TUserEvent>, TUserEvent: 'static>(
-    Takeable<Result<State<TApplication, TUserEvent>, TApplication::Error>>,
-);
+impl<T: 'static> EventSuspended<T> {
+    /// The name of the variant, for describing the event without formatting its contents.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Self::NewEvents(_) => "NewEvents",
+            Self::WindowEvent { .. } => "WindowEvent",
+            Self::DeviceEvent { .. } => "DeviceEvent",
+            Self::DragDrop { .. } => "DragDrop",
+            Self::ThemeChanged { .. } => "ThemeChanged",
+            Self::Ime { .. } => "Ime",
+            Self::ScaleFactorChanged { .. } => "ScaleFactorChanged",
+            Self::UserEvent(_) => "UserEvent",
+            Self::AboutToWait => "AboutToWait",
+            Self::MemoryWarning => "MemoryWarning",
+        }
+    }
+
+    pub fn user_event_ref(&self) -> Option<&T> {
+        match self {
+            Self::UserEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Returns the user event, or the event itself if it is not a user event.
+    #[allow(clippy::result_large_err)]
+    pub fn take_user_event(self) -> Result<T, Self> {
+        match self {
+            Self::UserEvent(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+
+    pub fn is_new_events(&self) -> bool {
+        matches!(self, Self::NewEvents(_))
+    }
+
+    pub fn start_cause(&self) -> Option<&winit::event::StartCause> {
+        match self {
+            Self::NewEvents(cause) => Some(cause),
+            _ => None,
+        }
+    }
+
+    pub fn is_about_to_wait(&self) -> bool {
+        matches!(self, Self::AboutToWait)
+    }
+
+    pub fn is_memory_warning(&self) -> bool {
+        matches!(self, Self::MemoryWarning)
+    }
+}
+
+impl<T: 'static> From<EventResumed<T>> for EventSuspended<T> {
+    fn from(event: EventResumed<T>) -> Self {
+        match event {
+            EventResumed::NewEvents(cause) => Self::NewEvents(cause),
+            EventResumed::WindowEvent { window_id, event } => {
+                Self::WindowEvent { window_id, event }
+            }
+            EventResumed::DeviceEvent { device_id, event } => {
+                Self::DeviceEvent { device_id, event }
+            }
+            EventResumed::DragDrop { window_id, event } => Self::DragDrop { window_id, event },
+            EventResumed::ThemeChanged { window_id, theme } => {
+                Self::ThemeChanged { window_id, theme }
+            }
+            EventResumed::Ime { window_id, event } => Self::Ime { window_id, event },
+            EventResumed::ScaleFactorChanged {
+                window_id,
+                scale_factor,
+                inner_size_writer,
+            } => Self::ScaleFactorChanged {
+                window_id,
+                scale_factor,
+                inner_size_writer,
+            },
+            EventResumed::UserEvent(event) => Self::UserEvent(event),
+            EventResumed::AboutToWait => Self::AboutToWait,
+            EventResumed::MemoryWarning => Self::MemoryWarning,
+        }
+    }
+}
+
+/// Formats an event without requiring the user event to implement [`std::fmt::Debug`].
+pub(crate) struct EventDescription<'a, TEvent>(pub &'a TEvent);
+
+impl<T: 'static> std::fmt::Display for EventDescription<'_, EventResumed<T>> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            EventResumed::NewEvents(cause) => write!(f, "NewEvents({cause:?})"),
+            EventResumed::WindowEvent { event, .. } => write!(f, "WindowEvent({event:?})"),
+            EventResumed::DeviceEvent { event, .. } => write!(f, "DeviceEvent({event:?})"),
+            EventResumed::DragDrop { event, .. } => write!(f, "DragDrop({event:?})"),
+            EventResumed::ThemeChanged { theme, .. } => write!(f, "ThemeChanged({theme:?})"),
+            EventResumed::Ime { event, .. } => write!(f, "Ime({event:?})"),
+            EventResumed::ScaleFactorChanged { scale_factor, .. } => {
+                write!(f, "ScaleFactorChanged({scale_factor})")
+            }
+            EventResumed::UserEvent(_) => write!(f, "UserEvent"),
+            EventResumed::AboutToWait => write!(f, "AboutToWait"),
+            EventResumed::MemoryWarning => write!(f, "MemoryWarning"),
+        }
+    }
+}
+
+impl<T: 'static> std::fmt::Display for EventDescription<'_, EventSuspended<T>> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            EventSuspended::NewEvents(cause) => write!(f, "NewEvents({cause:?})"),
+            EventSuspended::WindowEvent { event, .. } => write!(f, "WindowEvent({event:?})"),
+            EventSuspended::DeviceEvent { event, .. } => write!(f, "DeviceEvent({event:?})"),
+            EventSuspended::DragDrop { event, .. } => write!(f, "DragDrop({event:?})"),
+            EventSuspended::ThemeChanged { theme, .. } => write!(f, "ThemeChanged({theme:?})"),
+            EventSuspended::Ime { event, .. } => write!(f, "Ime({event:?})"),
+            EventSuspended::ScaleFactorChanged { scale_factor, .. } => {
+                write!(f, "ScaleFactorChanged({scale_factor})")
+            }
+            EventSuspended::UserEvent(_) => write!(f, "UserEvent"),
+            EventSuspended::AboutToWait => write!(f, "AboutToWait"),
+            EventSuspended::MemoryWarning => write!(f, "MemoryWarning"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApplicationStateKind {
+    Uninitialized,
+    Resumed,
+    Suspended,
+    Exited,
+    Error,
+}
+
+/// Drives an application as a [`winit::application::ApplicationHandler`].
+///
+/// Use [`run`] unless you need to drive the event loop yourself, for example with
+/// [`winit::platform::run_on_demand::EventLoopExtRunOnDemand::run_app_on_demand`].
+pub struct Adapter<TApplication: Application<TUserEvent>, TUserEvent: 'static> {
+    state: Takeable<FallibleState<TApplication, TUserEvent>>,
+    options: Options<TApplication, TUserEvent>,
+    pending_resizes: Vec<(winit::window::WindowId, winit::dpi::PhysicalSize<u32>)>,
+    #[cfg(not(target_arch = "wasm32"))]
+    shutdown_watchdog: Option<ShutdownWatchdog>,
+    last_frame: Option<Instant>,
+    /// The events delivered to the application, collected while wrapped in a
+    /// [`StateTransitionHistory`].
+    event_log: Option<Vec<(Instant, String)>>,
+}
 
 impl<TApplication: Application<TUserEvent>, TUserEvent: 'static> Adapter<TApplication, TUserEvent> {
     pub fn new(state: TApplication::Uninitialized) -> Self {
-        Self(Takeable::new(Ok(State::Uninitialized(state))))
+        Self::with_options(state, Options::default())
+    }
+
+    pub(crate) fn with_options(
+        state: TApplication::Uninitialized,
+        options: Options<TApplication, TUserEvent>,
+    ) -> Self {
+        Self {
+            state: Takeable::new(Ok(State::Uninitialized(state))),
+            options,
+            pending_resizes: Vec::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            shutdown_watchdog: None,
+            last_frame: None,
+            event_log: None,
+        }
+    }
+
+    pub fn state_kind(&self) -> ApplicationStateKind {
+        match self.state.peek() {
+            Ok(State::Uninitialized(_)) => ApplicationStateKind::Uninitialized,
+            Ok(State::Resumed(_)) => ApplicationStateKind::Resumed,
+            Ok(State::Suspended(_)) => ApplicationStateKind::Suspended,
+            Ok(State::Exited(_)) => ApplicationStateKind::Exited,
+            Err(_) => ApplicationStateKind::Error,
+        }
+    }
+
+    /// Returns `true` until the application has been initialized.
+    pub fn is_uninitialized(&self) -> bool {
+        self.state_kind() == ApplicationStateKind::Uninitialized
+    }
+
+    /// Returns `true` while the application is resumed.
+    pub fn is_resumed(&self) -> bool {
+        self.state_kind() == ApplicationStateKind::Resumed
+    }
+
+    /// Returns `true` while the application is suspended.
+    pub fn is_suspended(&self) -> bool {
+        self.state_kind() == ApplicationStateKind::Suspended
+    }
+
+    /// Returns `true` once the application has exited without an error.
+    pub fn is_exited(&self) -> bool {
+        self.state_kind() == ApplicationStateKind::Exited
+    }
+
+    /// Returns `true` once a state method has returned an error or panicked.
+    pub fn has_error(&self) -> bool {
+        self.state_kind() == ApplicationStateKind::Error
+    }
+
+    /// Returns the resumed state while the application is resumed.
+    pub fn resumed_state(&self) -> Option<&TApplication::Resumed> {
+        match self.state.peek() {
+            Ok(State::Resumed(state)) => Some(state),
+            _ => None,
+        }
+    }
+
+    /// Like [`Self::resumed_state`] but allows replacing the state, for example to restore a
+    /// snapshot.
+    pub fn resumed_state_mut(&mut self) -> Option<&mut TApplication::Resumed> {
+        match self.state.peek_mut() {
+            Ok(State::Resumed(state)) => Some(state),
+            _ => None,
+        }
+    }
+
+    /// Returns the error that ended the application, if any.
+    pub fn last_error(&self) -> Option<&TApplication::Error> {
+        self.state.peek().as_ref().err()?.as_ref()
+    }
+
+    /// Moves the error that ended the application out of the adapter. [`Self::exit`] panics if
+    /// it is called after the error was taken.
+    pub fn take_error(&mut self) -> Option<TApplication::Error> {
+        self.state.peek_mut().as_mut().err()?.take()
     }
 
     pub fn exit(self) -> Result<TApplication::Exited, TApplication::Error> {
-        Ok(match self.0.get()? {
+        let state = self
+            .state
+            .map(|state| state.map_err(|error| error.unwrap_or_else(|| taken_error())))
+            .into_inner()?;
+        Ok(match state {
             State::Uninitialized(_) => invalid_transition(),
             State::Resumed(_) => invalid_transition(),
             State::Suspended(_) => invalid_transition(),
@@ -136,66 +887,264 @@ enum State<TApplicationState: Application<TUserEvent>, TUserEvent: 'static> {
     Exited(TApplicationState::Exited),
 }
 
+/// The error is `None` once it has been taken with [`Adapter::take_error`] or when a state method
+/// panicked.
+type FallibleState<TApplication, TUserEvent> = Result<
+    State<TApplication, TUserEvent>,
+    Option<<TApplication as Application<TUserEvent>>::Error>,
+>;
+
 fn invalid_transition() -> ! {
     unreachable!("invalid transition")
 }
 
+fn taken_error() -> ! {
+    panic!("the application error was taken with Adapter::take_error or a state method panicked")
+}
+
 impl<TApplicationState: Application<TUserEvent>, TUserEvent: 'static>
     Adapter<TApplicationState, TUserEvent>
 {
     fn transition<
         F: FnOnce(
             State<TApplicationState, TUserEvent>,
+            &mut Options<TApplicationState, TUserEvent>,
         ) -> Result<State<TApplicationState, TUserEvent>, TApplicationState::Error>,
     >(
         &mut self,
         event_loop: &winit::event_loop::ActiveEventLoop,
         f: F,
     ) {
-        self.0.transition(|fallible_state| {
-            fallible_state.and_then(|state| f(state).inspect_err(|_| event_loop.exit()))
-        })
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "winit_ext::transition",
+            application = std::any::type_name::<TApplicationState>(),
+            from = ?self.state_kind(),
+            to = tracing::field::Empty,
+        )
+        .entered();
+        #[cfg(feature = "log")]
+        let from = self.state_kind();
+        let Self { state, options, .. } = self;
+        state.transition_recovering(
+            |fallible_state| {
+                fallible_state.and_then(|state| {
+                    f(state, options)
+                        .inspect_err(|error| {
+                            for plugin in &mut options.plugins {
+                                plugin.on_error(event_loop, error)
+                            }
+                            if let Some(error_handler) = &mut options.error_handler {
+                                error_handler(error)
+                            }
+                            event_loop.exit()
+                        })
+                        .map_err(Some)
+                })
+            },
+            // A panicking state method leaves no state behind, so the adapter reports an error
+            // without one, like after `take_error`.
+            || Err(None),
+        );
+        #[cfg(not(target_arch = "wasm32"))]
+        self.watch_shutdown(event_loop);
+        #[cfg(feature = "tracing")]
+        span.record("to", tracing::field::debug(self.state_kind()));
+        #[cfg(feature = "log")]
+        match self.state_kind() {
+            to if to == from => {}
+            ApplicationStateKind::Error => log::debug!(
+                target: "winit_ext",
+                "{}: error in {from:?} state",
+                std::any::type_name::<TApplicationState>()
+            ),
+            to => log::debug!(
+                target: "winit_ext",
+                "{}: {from:?} -> {to:?}",
+                std::any::type_name::<TApplicationState>()
+            ),
+        }
     }
-}
 
-impl<TApplicationState: Application<TUserEvent>, TUserEvent: 'static>
-    winit::application::ApplicationHandler<TUserEvent> for Adapter<TApplicationState, TUserEvent>
-{
-    fn new_events(
+    /// Starts the shutdown watchdog once an exit has been requested.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn watch_shutdown(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        if let Some(timeout) = self.options.shutdown_timeout {
+            if self.shutdown_watchdog.is_none() && event_loop.exiting() {
+                self.shutdown_watchdog = Some(ShutdownWatchdog::start(timeout));
+            }
+        }
+    }
+
+    /// Postpones the next wake-up until the next frame is due when a maximum frame rate is set.
+    fn limit_frame_rate(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        let Some(frame_period) = self.options.frame_period else {
+            return;
+        };
+        if event_loop.exiting() {
+            return;
+        }
+        let now = Instant::now();
+        let deadline = match self.last_frame {
+            Some(last_frame) if now < last_frame + frame_period => last_frame + frame_period,
+            _ => {
+                self.last_frame = Some(now);
+                now + frame_period
+            }
+        };
+        match event_loop.control_flow() {
+            winit::event_loop::ControlFlow::Poll => {}
+            winit::event_loop::ControlFlow::WaitUntil(instant) if instant < deadline => {}
+            _ => return,
+        }
+        event_loop.set_control_flow(winit::event_loop::ControlFlow::WaitUntil(deadline));
+    }
+
+    /// Delivers the pending resizes of the given window, or of all windows if `None`.
+    fn flush_resizes(
         &mut self,
         event_loop: &winit::event_loop::ActiveEventLoop,
-        cause: winit::event::StartCause,
+        window_id: Option<winit::window::WindowId>,
     ) {
-        self.transition(event_loop, |state| {
-            Ok(match state {
-                State::Uninitialized(state) => match cause {
-                    winit::event::StartCause::Init => State::Resumed(state.initialize(event_loop)?),
-                    _ => invalid_transition(),
+        let mut index = 0;
+        while index < self.pending_resizes.len() {
+            let (pending_window_id, size) = self.pending_resizes[index];
+            if window_id.is_some_and(|window_id| window_id != pending_window_id) {
+                index += 1;
+                continue;
+            }
+            self.pending_resizes.swap_remove(index);
+            self.dispatch(
+                event_loop,
+                EventResumed::WindowEvent {
+                    window_id: pending_window_id,
+                    event: winit::event::WindowEvent::Resized(size),
                 },
-                State::Resumed(state) => {
-                    State::Resumed(state.handle(event_loop, EventResumed::NewEvents(cause))?)
-                }
-                State::Suspended(state) => {
-                    State::Suspended(state.handle(event_loop, EventSuspended::NewEvents(cause))?)
-                }
-                State::Exited(_) => invalid_transition(),
-            })
-        })
+            );
+        }
     }
 
-    fn user_event(&mut self, event_loop: &winit::event_loop::ActiveEventLoop, event: TUserEvent) {
-        self.transition(event_loop, |state| {
+    fn dispatch(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventResumed<TUserEvent>,
+    ) {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            application = std::any::type_name::<TApplicationState>(),
+            "dispatch {}",
+            EventDescription(&event)
+        );
+        #[cfg(feature = "log")]
+        log::trace!(
+            target: "winit_ext",
+            "{}: dispatch {}",
+            std::any::type_name::<TApplicationState>(),
+            EventDescription(&event)
+        );
+        if let Some(event_filter) = &mut self.options.event_filter {
+            if !event_filter(&event) {
+                return;
+            }
+        }
+        if let Some(remaining_events) = &mut self.options.remaining_events {
+            if *remaining_events == 0 {
+                event_loop.exit();
+                return;
+            }
+            *remaining_events -= 1;
+            if *remaining_events == 0 {
+                event_loop.exit();
+            }
+        }
+        if let Some(event_log) = &mut self.event_log {
+            event_log.push((Instant::now(), EventDescription(&event).to_string()));
+        }
+        let started = self
+            .options
+            .frame_budget
+            .map(|budget| (budget, Instant::now()));
+        self.transition(event_loop, |state, options| {
             Ok(match state {
                 State::Uninitialized(_) => invalid_transition(),
                 State::Resumed(state) => {
-                    State::Resumed(state.handle(event_loop, EventResumed::UserEvent(event))?)
+                    for plugin in &mut options.plugins {
+                        plugin.on_event_resumed(event_loop, &event)
+                    }
+                    State::Resumed(state.handle(event_loop, event)?)
                 }
                 State::Suspended(state) => {
-                    State::Suspended(state.handle(event_loop, EventSuspended::UserEvent(event))?)
+                    let event = event.into();
+                    for plugin in &mut options.plugins {
+                        plugin.on_event_suspended(event_loop, &event)
+                    }
+                    State::Suspended(state.handle(event_loop, event)?)
                 }
                 State::Exited(_) => invalid_transition(),
             })
-        })
+        });
+        if let Some((budget, started)) = started {
+            let actual = started.elapsed();
+            if actual > budget {
+                log::warn!(
+                    target: "winit_ext",
+                    "frame overrun: {}ms (budget {}ms)",
+                    actual.as_millis(),
+                    budget.as_millis()
+                );
+            }
+        }
+        if let Some(continue_condition) = &mut self.options.continue_condition {
+            if let Some(Ok(State::Resumed(state))) = self.state.as_ref() {
+                if !continue_condition(state) {
+                    event_loop.exit()
+                }
+            }
+        }
+    }
+}
+
+impl<TApplicationState: Application<TUserEvent>, TUserEvent: 'static>
+    winit::application::ApplicationHandler<TUserEvent> for Adapter<TApplicationState, TUserEvent>
+{
+    fn new_events(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        cause: winit::event::StartCause,
+    ) {
+        match cause {
+            winit::event::StartCause::Init => self.transition(event_loop, |state, options| {
+                Ok(match state {
+                    State::Uninitialized(state) => {
+                        if let Some(observer) = &mut options.observer {
+                            observer.will_initialize(event_loop)
+                        }
+                        let state = match state.initialize(event_loop)? {
+                            InitialState::Resumed(state) => State::Resumed(state),
+                            InitialState::Suspended(state) => State::Suspended(state),
+                        };
+                        for plugin in &mut options.plugins {
+                            plugin.on_initialize(event_loop)
+                        }
+                        if let Some(observer) = &mut options.observer {
+                            observer.did_initialize(event_loop)
+                        }
+                        state
+                    }
+                    State::Resumed(_) => invalid_transition(),
+                    State::Suspended(_) => invalid_transition(),
+                    State::Exited(_) => invalid_transition(),
+                })
+            }),
+            _ => self.dispatch(event_loop, EventResumed::NewEvents(cause)),
+        }
+    }
+
+    fn user_event(&mut self, event_loop: &winit::event_loop::ActiveEventLoop, event: TUserEvent) {
+        self.dispatch(event_loop, EventResumed::UserEvent(event));
+        if let Some(event_queue) = &self.options.event_queue {
+            event_queue.on_user_event_processed()
+        }
     }
 
     fn device_event(
@@ -204,40 +1153,33 @@ impl<TApplicationState: Application<TUserEvent>, TUserEvent: 'static>
         device_id: winit::event::DeviceId,
         event: winit::event::DeviceEvent,
     ) {
-        self.transition(event_loop, |state| {
-            Ok(match state {
-                State::Uninitialized(_) => invalid_transition(),
-                State::Resumed(state) => State::Resumed(
-                    state.handle(event_loop, EventResumed::DeviceEvent { device_id, event })?,
-                ),
-                State::Suspended(state) => State::Suspended(
-                    state.handle(event_loop, EventSuspended::DeviceEvent { device_id, event })?,
-                ),
-                State::Exited(_) => invalid_transition(),
-            })
-        })
+        self.dispatch(event_loop, EventResumed::DeviceEvent { device_id, event })
     }
 
     fn about_to_wait(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
-        self.transition(event_loop, |state| {
-            Ok(match state {
-                State::Uninitialized(_) => invalid_transition(),
-                State::Resumed(state) => {
-                    State::Resumed(state.handle(event_loop, EventResumed::AboutToWait)?)
-                }
-                State::Suspended(state) => {
-                    State::Suspended(state.handle(event_loop, EventSuspended::AboutToWait)?)
-                }
-                State::Exited(_) => invalid_transition(),
-            })
-        })
+        self.flush_resizes(event_loop, None);
+        self.dispatch(event_loop, EventResumed::AboutToWait);
+        self.limit_frame_rate(event_loop)
     }
 
     fn suspended(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
-        self.transition(event_loop, |state| {
+        self.flush_resizes(event_loop, None);
+        self.transition(event_loop, |state, options| {
             Ok(match state {
                 State::Uninitialized(_) => invalid_transition(),
-                State::Resumed(state) => State::Suspended(state.suspend(event_loop)?),
+                State::Resumed(state) => {
+                    if let Some(observer) = &mut options.observer {
+                        observer.will_suspend(event_loop)
+                    }
+                    let state = state.suspend(event_loop)?;
+                    for plugin in &mut options.plugins {
+                        plugin.on_suspend(event_loop)
+                    }
+                    if let Some(observer) = &mut options.observer {
+                        observer.did_suspend(event_loop)
+                    }
+                    State::Suspended(state)
+                }
                 State::Suspended(state) => State::Suspended(state),
                 State::Exited(_) => invalid_transition(),
             })
@@ -245,37 +1187,69 @@ impl<TApplicationState: Application<TUserEvent>, TUserEvent: 'static>
     }
 
     fn exiting(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
-        self.transition(event_loop, |state| {
-            Ok(match state {
-                State::Uninitialized(_) => invalid_transition(),
-                State::Resumed(state) => State::Exited(state.exit(event_loop)?),
-                State::Suspended(state) => State::Exited(state.exit(event_loop)?),
-                State::Exited(_) => invalid_transition(),
-            })
-        })
-    }
-
-    fn memory_warning(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
-        self.transition(event_loop, |state| {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.watch_shutdown(event_loop);
+        self.flush_resizes(event_loop, None);
+        self.transition(event_loop, |state, options| {
             Ok(match state {
                 State::Uninitialized(_) => invalid_transition(),
                 State::Resumed(state) => {
-                    State::Resumed(state.handle(event_loop, EventResumed::MemoryWarning)?)
+                    if let Some(observer) = &mut options.observer {
+                        observer.will_exit(event_loop)
+                    }
+                    let state = state.exit(event_loop)?;
+                    for plugin in &mut options.plugins {
+                        plugin.on_exit(event_loop)
+                    }
+                    if let Some(observer) = &mut options.observer {
+                        observer.did_exit(event_loop)
+                    }
+                    State::Exited(state)
                 }
                 State::Suspended(state) => {
-                    State::Suspended(state.handle(event_loop, EventSuspended::MemoryWarning)?)
+                    if let Some(observer) = &mut options.observer {
+                        observer.will_exit(event_loop)
+                    }
+                    let state = state.exit(event_loop)?;
+                    for plugin in &mut options.plugins {
+                        plugin.on_exit(event_loop)
+                    }
+                    if let Some(observer) = &mut options.observer {
+                        observer.did_exit(event_loop)
+                    }
+                    State::Exited(state)
                 }
                 State::Exited(_) => invalid_transition(),
             })
-        })
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.shutdown_watchdog = None;
+        }
+    }
+
+    fn memory_warning(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.dispatch(event_loop, EventResumed::MemoryWarning)
     }
 
     fn resumed(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
-        self.transition(event_loop, |state| {
+        self.transition(event_loop, |state, options| {
             Ok(match state {
                 State::Uninitialized(_) => invalid_transition(),
                 State::Resumed(state) => State::Resumed(state),
-                State::Suspended(state) => State::Resumed(state.resume(event_loop)?),
+                State::Suspended(state) => {
+                    if let Some(observer) = &mut options.observer {
+                        observer.will_resume(event_loop)
+                    }
+                    let state = state.resume(event_loop)?;
+                    for plugin in &mut options.plugins {
+                        plugin.on_resume(event_loop)
+                    }
+                    if let Some(observer) = &mut options.observer {
+                        observer.did_resume(event_loop)
+                    }
+                    State::Resumed(state)
+                }
                 State::Exited(_) => invalid_transition(),
             })
         })
@@ -287,19 +1261,30 @@ impl<TApplicationState: Application<TUserEvent>, TUserEvent: 'static>
         window_id: winit::window::WindowId,
         event: winit::event::WindowEvent,
     ) {
-        self.transition(event_loop, |state| {
-            Ok(match state {
-                State::Uninitialized(_) => invalid_transition(),
-                State::Resumed(state) => State::Resumed(
-                    state.handle(event_loop, EventResumed::WindowEvent { window_id, event })?,
-                ),
-                State::Suspended(_) => {
-                    // TODO: Can we receive window events while suspended?
-                    invalid_transition()
+        if self.options.coalesce_resizes {
+            match event {
+                winit::event::WindowEvent::Resized(size) => {
+                    match self
+                        .pending_resizes
+                        .iter_mut()
+                        .find(|(pending_window_id, _)| *pending_window_id == window_id)
+                    {
+                        Some((_, pending_size)) => *pending_size = size,
+                        None => self.pending_resizes.push((window_id, size)),
+                    }
+                    return;
                 }
-                State::Exited(_) => invalid_transition(),
-            })
-        })
+                winit::event::WindowEvent::ScaleFactorChanged { .. }
+                | winit::event::WindowEvent::RedrawRequested => {
+                    self.flush_resizes(event_loop, Some(window_id))
+                }
+                _ => {}
+            }
+        }
+        self.dispatch(
+            event_loop,
+            EventResumed::from_window_event(window_id, event),
+        )
     }
 }
 
@@ -313,7 +1298,240 @@ pub fn run<TApplicationUninitialized: ApplicationUninitialized<TUserEvent>, TUse
     event_loop: winit::event_loop::EventLoop<TUserEvent>,
     state: TApplicationUninitialized,
 ) -> EventLoopResult<ApplicationResult<TApplicationUninitialized::Application, TUserEvent>> {
-    let mut app = Adapter::<TApplicationUninitialized::Application, TUserEvent>::new(state);
-    event_loop.run_app(&mut app)?;
-    Ok(app.exit())
+    ApplicationRunnerBuilder::<TApplicationUninitialized::Application, TUserEvent>::new()
+        .run(event_loop, state)
+}
+
+pub fn run_infallible<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: 'static,
+>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    state: TApplicationUninitialized,
+) -> EventLoopResult<<TApplicationUninitialized::Application as Application<TUserEvent>>::Exited>
+where
+    TApplicationUninitialized::Application:
+        Application<TUserEvent, Error = std::convert::Infallible>,
+{
+    Ok(run(event_loop, state)?.unwrap_or_else(|error| match error {}))
+}
+
+pub fn run_with_proxy<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: 'static,
+    F: FnOnce(AppEventSender<TUserEvent>),
+>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    state: TApplicationUninitialized,
+    setup: F,
+) -> EventLoopResult<ApplicationResult<TApplicationUninitialized::Application, TUserEvent>> {
+    setup(AppEventSender::from(event_loop.create_proxy()));
+    run(event_loop, state)
+}
+
+/// Like [`run_with_proxy`] but hands out a plain [`std::sync::mpsc::Sender`]. A background thread
+/// forwards the sent events to the event loop. The thread stops once every sender is dropped, or
+/// when the first event sent after the event loop has exited fails to arrive. Until then it stays
+/// blocked waiting for events, also after this function returns, so drop the senders when they
+/// are no longer needed.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_with_sender<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: Send + 'static,
+    F: FnOnce(std::sync::mpsc::Sender<TUserEvent>),
+>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    state: TApplicationUninitialized,
+    setup: F,
+) -> EventLoopResult<ApplicationResult<TApplicationUninitialized::Application, TUserEvent>> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let proxy = event_loop.create_proxy();
+    std::thread::spawn(move || {
+        while let Ok(event) = receiver.recv() {
+            if proxy.send_event(event).is_err() {
+                break;
+            }
+        }
+    });
+    setup(sender);
+    run(event_loop, state)
+}
+
+/// Like [`run`] but sends `shutdown_event` to the application whenever the process receives
+/// SIGINT or SIGTERM. The signals terminate the process again once the event loop exits.
+///
+/// If the handlers cannot be installed, a warning is logged and the signals keep their default
+/// behaviour.
+#[cfg(all(unix, feature = "signals"))]
+pub fn run_with_signals<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: Clone + Send + 'static,
+>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    state: TApplicationUninitialized,
+    shutdown_event: TUserEvent,
+) -> EventLoopResult<ApplicationResult<TApplicationUninitialized::Application, TUserEvent>> {
+    let _signal_forwarder = signals::SignalForwarder::install(
+        event_loop.create_proxy(),
+        shutdown_event,
+    )
+    .inspect_err(
+        |error| log::warn!(target: "winit_ext", "failed to install signal handlers: {error}"),
+    )
+    .ok();
+    run(event_loop, state)
+}
+
+/// Like [`run`] but returns immediately, as required on the main thread of a browser. See
+/// [`ApplicationRunnerBuilder::spawn`].
+#[cfg(target_arch = "wasm32")]
+pub fn run_web<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: 'static,
+>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    state: TApplicationUninitialized,
+) where
+    Adapter<TApplicationUninitialized::Application, TUserEvent>: 'static,
+{
+    ApplicationRunnerBuilder::<TApplicationUninitialized::Application, TUserEvent>::new()
+        .spawn(event_loop, state)
+}
+
+/// Runs an application that keeps a single state for its whole lifetime. Suspending and resuming
+/// leave the state untouched.
+pub fn run_simple<TUserEvent: 'static, TState: 'static, TError: 'static>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    initialize: impl FnOnce(&winit::event_loop::ActiveEventLoop) -> Result<TState, TError> + 'static,
+    handle: impl FnMut(
+            &mut TState,
+            &winit::event_loop::ActiveEventLoop,
+            EventResumed<TUserEvent>,
+        ) -> Result<(), TError>
+        + 'static,
+) -> EventLoopResult<Result<(), TError>> {
+    run(
+        event_loop,
+        ClosureApplication::new(
+            initialize,
+            handle,
+            Ok,
+            |state, _event_loop| Ok(state),
+            |_state| Ok(()),
+        ),
+    )
+}
+
+/// Runs the application until `should_continue` returns `false` for the resumed state after an
+/// event was handled.
+pub fn run_until<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: 'static,
+    F: FnMut(&<TApplicationUninitialized::Application as Application<TUserEvent>>::Resumed) -> bool
+        + 'static,
+>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    state: TApplicationUninitialized,
+    should_continue: F,
+) -> EventLoopResult<ApplicationResult<TApplicationUninitialized::Application, TUserEvent>> {
+    ApplicationRunnerBuilder::<TApplicationUninitialized::Application, TUserEvent>::new()
+        .with_continue_condition(should_continue)
+        .run(event_loop, state)
+}
+
+/// Runs the application and exits after exactly `n` events have been delivered to it.
+pub fn run_n_events<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: 'static,
+>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    state: TApplicationUninitialized,
+    n: usize,
+) -> EventLoopResult<ApplicationResult<TApplicationUninitialized::Application, TUserEvent>> {
+    ApplicationRunnerBuilder::<TApplicationUninitialized::Application, TUserEvent>::new()
+        .with_event_limit(n)
+        .run(event_loop, state)
+}
+
+/// Like [`run`] but limits the frame rate to `max_fps`, see
+/// [`ApplicationRunnerBuilder::with_max_fps`]. Recommended for rendering applications that poll,
+/// which otherwise keep a CPU core busy.
+pub fn run_with_frame_limit<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: 'static,
+>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    state: TApplicationUninitialized,
+    max_fps: u32,
+) -> EventLoopResult<ApplicationResult<TApplicationUninitialized::Application, TUserEvent>> {
+    ApplicationRunnerBuilder::<TApplicationUninitialized::Application, TUserEvent>::new()
+        .with_max_fps(max_fps)
+        .run(event_loop, state)
+}
+
+/// Like [`run`] but wraps application errors in a [`TransitionError`] that records the state and
+/// event that produced them.
+pub fn run_with_context<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: 'static,
+>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    state: TApplicationUninitialized,
+) -> EventLoopResult<
+    ApplicationResult<ErrorContext<TApplicationUninitialized::Application>, TUserEvent>,
+> {
+    run(event_loop, ErrorContext::wrap(state))
+}
+
+/// Like [`run`] but prints any error to stderr and exits the process with status 1.
+pub fn run_or_exit<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: 'static,
+>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    state: TApplicationUninitialized,
+) -> <TApplicationUninitialized::Application as Application<TUserEvent>>::Exited
+where
+    <TApplicationUninitialized::Application as Application<TUserEvent>>::Error: std::fmt::Display,
+{
+    match run(event_loop, state) {
+        Ok(Ok(exited)) => exited,
+        Ok(Err(error)) => {
+            eprintln!("application error: {error}");
+            std::process::exit(1)
+        }
+        Err(error) => {
+            eprintln!("event loop error: {error}");
+            std::process::exit(1)
+        }
+    }
+}
+
+/// Like [`run_or_exit`] but also exits the process when the application exits, with the code
+/// given by [`HasExitCode::exit_code`].
+pub fn run_with_exit_code<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: 'static,
+>(
+    event_loop: winit::event_loop::EventLoop<TUserEvent>,
+    state: TApplicationUninitialized,
+) -> !
+where
+    TApplicationUninitialized::Application: HasExitCode<TUserEvent>,
+    <TApplicationUninitialized::Application as Application<TUserEvent>>::Error: std::fmt::Display,
+{
+    let exited = run_or_exit(event_loop, state);
+    std::process::exit(<TApplicationUninitialized::Application as HasExitCode<
+        TUserEvent,
+    >>::exit_code(&exited))
+}
+
+pub fn run_with_builder<
+    TApplicationUninitialized: ApplicationUninitialized<TUserEvent>,
+    TUserEvent: 'static,
+>(
+    mut builder: winit::event_loop::EventLoopBuilder<TUserEvent>,
+    state: TApplicationUninitialized,
+) -> EventLoopResult<ApplicationResult<TApplicationUninitialized::Application, TUserEvent>> {
+    run(builder.build()?, state)
 }
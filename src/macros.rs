@@ -0,0 +1,202 @@
+/// Declares an uninhabited application type and implements [`Application`](crate::Application)
+/// for it.
+///
+/// The implementation is generic over the user event type unless one is given as in
+/// `MyApp<MyUserEvent> { .. }`, so without one the states must be generic over it too.
+///
+/// ```
+/// use std::convert::Infallible;
+///
+/// use winit::event_loop::ActiveEventLoop;
+/// use winit_ext::{
+///     ApplicationResumed, ApplicationSuspended, ApplicationUninitialized, InitialState,
+/// };
+///
+/// winit_ext::application_states! {
+///     MyApp {
+///         Uninitialized = Uninitialized,
+///         Resumed = Resumed,
+///         Suspended = Suspended,
+///         Exited = (),
+///         Error = Infallible,
+///     }
+/// }
+///
+/// struct Uninitialized;
+/// struct Resumed;
+/// struct Suspended;
+///
+/// impl<T: 'static> ApplicationUninitialized<T> for Uninitialized {
+///     type Application = MyApp;
+///
+///     fn initialize(self, _: &ActiveEventLoop) -> Result<InitialState<MyApp, T>, Infallible> {
+///         Ok(InitialState::Suspended(Suspended))
+///     }
+/// }
+///
+/// impl<T: 'static> ApplicationResumed<T> for Resumed {
+///     type Application = MyApp;
+///
+///     fn suspend(self, _: &ActiveEventLoop) -> Result<Suspended, Infallible> {
+///         Ok(Suspended)
+///     }
+///
+///     fn exit(self, _: &ActiveEventLoop) -> Result<(), Infallible> {
+///         Ok(())
+///     }
+/// }
+///
+/// impl<T: 'static> ApplicationSuspended<T> for Suspended {
+///     type Application = MyApp;
+///
+///     fn resume(self, _: &ActiveEventLoop) -> Result<Resumed, Infallible> {
+///         Ok(Resumed)
+///     }
+///
+///     fn exit(self, _: &ActiveEventLoop) -> Result<(), Infallible> {
+///         Ok(())
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! application_states {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident {
+            Uninitialized = $uninitialized:ty,
+            Resumed = $resumed:ty,
+            Suspended = $suspended:ty,
+            Exited = $exited:ty,
+            Error = $error:ty $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {}
+
+        impl<TUserEvent: 'static> $crate::Application<TUserEvent> for $name {
+            type Uninitialized = $uninitialized;
+            type Resumed = $resumed;
+            type Suspended = $suspended;
+            type Exited = $exited;
+            type Error = $error;
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident<$user_event:ty> {
+            Uninitialized = $uninitialized:ty,
+            Resumed = $resumed:ty,
+            Suspended = $suspended:ty,
+            Exited = $exited:ty,
+            Error = $error:ty $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {}
+
+        impl $crate::Application<$user_event> for $name {
+            type Uninitialized = $uninitialized;
+            type Resumed = $resumed;
+            type Suspended = $suspended;
+            type Exited = $exited;
+            type Error = $error;
+        }
+    };
+}
+
+/// Matches an event inside [`ApplicationResumed::handle`](crate::ApplicationResumed::handle) or
+/// [`ApplicationSuspended::handle`](crate::ApplicationSuspended::handle) and returns `Ok(self)`
+/// for every event that no arm matches.
+///
+/// The state is passed explicitly because macros cannot refer to `self` on their own. Every arm
+/// evaluates to the result of `handle`.
+///
+/// ```
+/// # use std::convert::Infallible;
+/// #
+/// use winit::{event::WindowEvent, event_loop::ActiveEventLoop, window::WindowId};
+/// use winit_ext::{ApplicationResumed, EventResumed};
+/// # use winit_ext::{ApplicationSuspended, ApplicationUninitialized, InitialState};
+/// #
+/// # winit_ext::application_states! {
+/// #     MyApp<()> {
+/// #         Uninitialized = Uninitialized,
+/// #         Resumed = Resumed,
+/// #         Suspended = Suspended,
+/// #         Exited = (),
+/// #         Error = Infallible,
+/// #     }
+/// # }
+/// #
+/// # struct Uninitialized;
+/// # struct Suspended;
+/// #
+/// # impl ApplicationUninitialized for Uninitialized {
+/// #     type Application = MyApp;
+/// #
+/// #     fn initialize(self, _: &ActiveEventLoop) -> Result<InitialState<MyApp>, Infallible> {
+/// #         Ok(InitialState::Suspended(Suspended))
+/// #     }
+/// # }
+/// #
+/// # impl ApplicationSuspended for Suspended {
+/// #     type Application = MyApp;
+/// #
+/// #     fn resume(self, _: &ActiveEventLoop) -> Result<Resumed, Infallible> {
+/// #         unimplemented!()
+/// #     }
+/// #
+/// #     fn exit(self, _: &ActiveEventLoop) -> Result<(), Infallible> {
+/// #         Ok(())
+/// #     }
+/// # }
+///
+/// struct Resumed {
+///     window_id: WindowId,
+///     frames: u64,
+/// }
+///
+/// impl ApplicationResumed for Resumed {
+///     type Application = MyApp;
+///
+///     fn handle(
+///         mut self,
+///         event_loop: &ActiveEventLoop,
+///         event: EventResumed<()>,
+///     ) -> Result<Self, Infallible> {
+///         winit_ext::event_match!(self, event,
+///             // An arm with a guard.
+///             EventResumed::WindowEvent { window_id, event: WindowEvent::CloseRequested }
+///                 if window_id == self.window_id =>
+///             {
+///                 event_loop.exit();
+///                 Ok(self)
+///             },
+///             // An arm without a guard.
+///             EventResumed::AboutToWait => {
+///                 self.frames += 1;
+///                 Ok(self)
+///             },
+///             // Every other event returns `Ok(self)`.
+///         )
+///     }
+/// #
+/// #     fn suspend(self, _: &ActiveEventLoop) -> Result<Suspended, Infallible> {
+/// #         Ok(Suspended)
+/// #     }
+/// #
+/// #     fn exit(self, _: &ActiveEventLoop) -> Result<(), Infallible> {
+/// #         Ok(())
+/// #     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! event_match {
+    ($state:expr, $event:expr, $($pattern:pat $(if $guard:expr)? => $body:expr),* $(,)?) => {
+        match $event {
+            $($pattern $(if $guard)? => $body,)*
+            #[allow(unreachable_patterns)]
+            _ => Ok($state),
+        }
+    };
+}
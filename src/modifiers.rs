@@ -0,0 +1,41 @@
+use crate::EventResumed;
+
+/// Tracks the modifier keys that are currently held down.
+#[derive(Debug, Clone, Default)]
+pub struct ModifierKeyTracker(winit::event::Modifiers);
+
+impl ModifierKeyTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update<TUserEvent>(&mut self, event: &EventResumed<TUserEvent>) {
+        if let EventResumed::WindowEvent {
+            event: winit::event::WindowEvent::ModifiersChanged(modifiers),
+            ..
+        } = event
+        {
+            self.0 = *modifiers;
+        }
+    }
+
+    pub fn modifiers(&self) -> &winit::event::Modifiers {
+        &self.0
+    }
+
+    pub fn ctrl(&self) -> bool {
+        self.0.state().control_key()
+    }
+
+    pub fn shift(&self) -> bool {
+        self.0.state().shift_key()
+    }
+
+    pub fn alt(&self) -> bool {
+        self.0.state().alt_key()
+    }
+
+    pub fn logo(&self) -> bool {
+        self.0.state().super_key()
+    }
+}
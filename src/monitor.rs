@@ -0,0 +1,56 @@
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for winit::monitor::MonitorHandle {}
+}
+
+/// Convenience accessors for [`winit::monitor::MonitorHandle`].
+pub trait MonitorHandleExt: private::Sealed {
+    /// Returns the current refresh rate in hertz. Falls back to the highest refresh rate of the
+    /// video modes when the platform does not report the current one.
+    fn refresh_rate_hz(&self) -> Option<f64>;
+
+    /// Returns the name of the monitor, or `"Unknown monitor"` when it has none.
+    fn name_or_default(&self) -> String;
+
+    /// Returns `true` if this is the primary monitor. Platforms without the concept of a primary
+    /// monitor, such as Wayland, treat the monitor at the origin as the primary one.
+    fn is_primary_heuristic(&self, event_loop: &winit::event_loop::ActiveEventLoop) -> bool;
+
+    /// Returns the video mode with the highest resolution, then bit depth, then refresh rate.
+    fn best_video_mode(&self) -> Option<winit::monitor::VideoModeHandle>;
+}
+
+impl MonitorHandleExt for winit::monitor::MonitorHandle {
+    fn refresh_rate_hz(&self) -> Option<f64> {
+        self.refresh_rate_millihertz()
+            .or_else(|| {
+                self.video_modes()
+                    .map(|mode| mode.refresh_rate_millihertz())
+                    .max()
+            })
+            .map(|millihertz| f64::from(millihertz) / 1000.0)
+    }
+
+    fn name_or_default(&self) -> String {
+        self.name().unwrap_or_else(|| "Unknown monitor".to_string())
+    }
+
+    fn is_primary_heuristic(&self, event_loop: &winit::event_loop::ActiveEventLoop) -> bool {
+        match event_loop.primary_monitor() {
+            Some(primary) => primary == *self,
+            None => self.position() == winit::dpi::PhysicalPosition::new(0, 0),
+        }
+    }
+
+    fn best_video_mode(&self) -> Option<winit::monitor::VideoModeHandle> {
+        self.video_modes().max_by_key(|mode| {
+            let size = mode.size();
+            (
+                u64::from(size.width) * u64::from(size.height),
+                mode.bit_depth(),
+                mode.refresh_rate_millihertz(),
+            )
+        })
+    }
+}
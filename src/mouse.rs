@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use crate::EventResumed;
+
+/// Tracks which mouse buttons are held down in each window.
+#[derive(Debug, Clone, Default)]
+pub struct MouseButtonTracker(
+    HashMap<(winit::window::WindowId, winit::event::MouseButton), winit::event::ElementState>,
+);
+
+impl MouseButtonTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update<TUserEvent>(&mut self, event: &EventResumed<TUserEvent>) {
+        let EventResumed::WindowEvent { window_id, event } = event else {
+            return;
+        };
+        match event {
+            winit::event::WindowEvent::MouseInput { state, button, .. } => {
+                self.0.insert((*window_id, *button), *state);
+            }
+            winit::event::WindowEvent::Destroyed => self.0.retain(|(id, _), _| id != window_id),
+            _ => {}
+        }
+    }
+
+    pub fn is_pressed(
+        &self,
+        window_id: winit::window::WindowId,
+        button: winit::event::MouseButton,
+    ) -> bool {
+        self.0
+            .get(&(window_id, button))
+            .is_some_and(|state| state.is_pressed())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::Application;
+
+/// Observes the state transitions of an application, but not its events.
+///
+/// The `will_*` hooks are called right before the transition and the `did_*` hooks after it
+/// succeeded. Register an observer with [`crate::ApplicationRunnerBuilder::with_observer`].
+pub trait ApplicationLifecycleObserver<
+    TApplication: Application<TUserEvent>,
+    TUserEvent: 'static = (),
+>
+{
+    fn will_initialize(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+
+    fn did_initialize(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+
+    fn will_resume(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+
+    fn did_resume(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+
+    fn will_suspend(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+
+    fn did_suspend(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+
+    fn will_exit(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+
+    fn did_exit(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+}
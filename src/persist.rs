@@ -0,0 +1,68 @@
+use crate::{Adapter, Application, ApplicationStateKind};
+
+/// The error returned by [`restore_resumed_state`].
+#[derive(Debug)]
+pub enum RestoreError<E> {
+    /// The application was in the given state instead of resumed.
+    NotResumed(ApplicationStateKind),
+    Deserialize(E),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for RestoreError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotResumed(state) => {
+                write!(f, "cannot restore the resumed state in {state:?} state")
+            }
+            Self::Deserialize(error) => {
+                write!(f, "failed to deserialize the resumed state: {error}")
+            }
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for RestoreError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NotResumed(_) => None,
+            Self::Deserialize(error) => Some(error),
+        }
+    }
+}
+
+/// Serializes the resumed state with the given serializer, or returns `None` if the application is
+/// not resumed.
+pub fn export_resumed_state<TApplication, TUserEvent, TSerializer>(
+    adapter: &Adapter<TApplication, TUserEvent>,
+    serializer: TSerializer,
+) -> Option<Result<TSerializer::Ok, TSerializer::Error>>
+where
+    TApplication: Application<TUserEvent>,
+    TApplication::Resumed: serde::Serialize,
+    TUserEvent: 'static,
+    TSerializer: serde::Serializer,
+{
+    adapter
+        .resumed_state()
+        .map(|state| serde::Serialize::serialize(state, serializer))
+}
+
+/// Replaces the resumed state with one read from the given deserializer. The current state is kept
+/// if deserialization fails.
+pub fn restore_resumed_state<'de, TApplication, TUserEvent, TDeserializer>(
+    adapter: &mut Adapter<TApplication, TUserEvent>,
+    deserializer: TDeserializer,
+) -> Result<(), RestoreError<TDeserializer::Error>>
+where
+    TApplication: Application<TUserEvent>,
+    TApplication::Resumed: serde::Deserialize<'de>,
+    TUserEvent: 'static,
+    TDeserializer: serde::Deserializer<'de>,
+{
+    let kind = adapter.state_kind();
+    let state = adapter
+        .resumed_state_mut()
+        .ok_or(RestoreError::NotResumed(kind))?;
+    *state = serde::Deserialize::deserialize(deserializer).map_err(RestoreError::Deserialize)?;
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::{Application, EventResumed, EventSuspended};
+
+/// Observes an application without being part of it, for example to add logging or metrics.
+///
+/// The transition hooks are called after the transition succeeded and the event hooks right
+/// before the event is handled. Register plugins with
+/// [`crate::ApplicationRunnerBuilder::with_plugin`].
+pub trait ApplicationPlugin<TApplication: Application<TUserEvent>, TUserEvent: 'static = ()> {
+    fn on_initialize(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+
+    fn on_resume(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+
+    fn on_suspend(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+
+    fn on_exit(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {}
+
+    fn on_event_resumed(
+        &mut self,
+        _event_loop: &winit::event_loop::ActiveEventLoop,
+        _event: &EventResumed<TUserEvent>,
+    ) {
+    }
+
+    fn on_event_suspended(
+        &mut self,
+        _event_loop: &winit::event_loop::ActiveEventLoop,
+        _event: &EventSuspended<TUserEvent>,
+    ) {
+    }
+
+    fn on_error(
+        &mut self,
+        _event_loop: &winit::event_loop::ActiveEventLoop,
+        _error: &TApplication::Error,
+    ) {
+    }
+}
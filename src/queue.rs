@@ -0,0 +1,71 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// Sends user events but refuses new ones while `capacity` events have not been processed yet.
+///
+/// Register the queue with [`crate::ApplicationRunnerBuilder::with_event_queue`] so the adapter
+/// reports processed events. Every clone shares the same capacity. The queue must be the only
+/// sender of user events, because the adapter counts every user event as one sent by the queue.
+pub struct BoundedEventQueue<TUserEvent: 'static> {
+    proxy: winit::event_loop::EventLoopProxy<TUserEvent>,
+    in_flight: Arc<AtomicUsize>,
+    capacity: usize,
+}
+
+impl<TUserEvent: 'static> Clone for BoundedEventQueue<TUserEvent> {
+    fn clone(&self) -> Self {
+        Self {
+            proxy: self.proxy.clone(),
+            in_flight: self.in_flight.clone(),
+            capacity: self.capacity,
+        }
+    }
+}
+
+impl<TUserEvent: 'static> BoundedEventQueue<TUserEvent> {
+    pub fn new(proxy: winit::event_loop::EventLoopProxy<TUserEvent>, capacity: usize) -> Self {
+        Self {
+            proxy,
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            capacity,
+        }
+    }
+
+    /// Returns `false` if the queue is full or the event loop has exited and the event was
+    /// dropped.
+    pub fn try_send(&self, event: TUserEvent) -> bool {
+        if self
+            .in_flight
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |in_flight| {
+                (in_flight < self.capacity).then_some(in_flight + 1)
+            })
+            .is_err()
+        {
+            return false;
+        }
+        if self.proxy.send_event(event).is_err() {
+            self.on_user_event_processed();
+            return false;
+        }
+        true
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Acquire)
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Frees up one slot. Called by the adapter after every user event it dispatched.
+    pub fn on_user_event_processed(&self) {
+        let _ = self
+            .in_flight
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |in_flight| {
+                in_flight.checked_sub(1)
+            });
+    }
+}
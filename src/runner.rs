@@ -0,0 +1,198 @@
+use crate::{
+    Adapter, Application, ApplicationLifecycleObserver, ApplicationPlugin, ApplicationResult,
+    BoundedEventQueue, EventLoopResult, EventResumed,
+};
+
+type ErrorHandler<TApplication, TUserEvent> =
+    Box<dyn FnMut(&<TApplication as Application<TUserEvent>>::Error)>;
+type EventFilter<TUserEvent> = Box<dyn FnMut(&EventResumed<TUserEvent>) -> bool>;
+type ContinueCondition<TApplication, TUserEvent> =
+    Box<dyn FnMut(&<TApplication as Application<TUserEvent>>::Resumed) -> bool>;
+
+pub(crate) struct Options<TApplication: Application<TUserEvent>, TUserEvent: 'static> {
+    pub error_handler: Option<ErrorHandler<TApplication, TUserEvent>>,
+    pub event_filter: Option<EventFilter<TUserEvent>>,
+    pub continue_condition: Option<ContinueCondition<TApplication, TUserEvent>>,
+    pub remaining_events: Option<usize>,
+    pub plugins: Vec<Box<dyn ApplicationPlugin<TApplication, TUserEvent>>>,
+    pub coalesce_resizes: bool,
+    pub observer: Option<Box<dyn ApplicationLifecycleObserver<TApplication, TUserEvent>>>,
+    pub event_queue: Option<BoundedEventQueue<TUserEvent>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub shutdown_timeout: Option<std::time::Duration>,
+    pub frame_period: Option<std::time::Duration>,
+    pub frame_budget: Option<std::time::Duration>,
+}
+
+impl<TApplication: Application<TUserEvent>, TUserEvent: 'static> Default
+    for Options<TApplication, TUserEvent>
+{
+    fn default() -> Self {
+        Self {
+            error_handler: None,
+            event_filter: None,
+            continue_condition: None,
+            remaining_events: None,
+            plugins: Vec::new(),
+            coalesce_resizes: false,
+            observer: None,
+            event_queue: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            shutdown_timeout: None,
+            frame_period: None,
+            frame_budget: None,
+        }
+    }
+}
+
+/// Configures how an application is run. [`crate::run`] runs an application with the defaults.
+pub struct ApplicationRunnerBuilder<TApplication: Application<TUserEvent>, TUserEvent: 'static = ()>
+{
+    options: Options<TApplication, TUserEvent>,
+}
+
+impl<TApplication: Application<TUserEvent>, TUserEvent: 'static> Default
+    for ApplicationRunnerBuilder<TApplication, TUserEvent>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TApplication: Application<TUserEvent>, TUserEvent: 'static>
+    ApplicationRunnerBuilder<TApplication, TUserEvent>
+{
+    pub fn new() -> Self {
+        Self {
+            options: Options::default(),
+        }
+    }
+
+    /// Calls `f` with every error returned by a state method, right before the event loop is
+    /// asked to exit.
+    pub fn with_error_handler<F: FnMut(&TApplication::Error) + 'static>(mut self, f: F) -> Self {
+        self.options.error_handler = Some(Box::new(f));
+        self
+    }
+
+    /// Only passes events to the application for which `f` returns `true`. Suspended applications
+    /// receive the filtered events converted into [`crate::EventSuspended`].
+    pub fn with_event_filter<F: FnMut(&EventResumed<TUserEvent>) -> bool + 'static>(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.options.event_filter = Some(Box::new(f));
+        self
+    }
+
+    /// Asks the event loop to exit as soon as `f` returns `false`. `f` is called with the resumed
+    /// state after every successful call to [`crate::ApplicationResumed::handle`].
+    pub fn with_continue_condition<F: FnMut(&TApplication::Resumed) -> bool + 'static>(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.options.continue_condition = Some(Box::new(f));
+        self
+    }
+
+    /// Asks the event loop to exit after `n` events have been delivered to the application. Events
+    /// rejected by the event filter are not counted.
+    pub fn with_event_limit(mut self, n: usize) -> Self {
+        self.options.remaining_events = Some(n);
+        self
+    }
+
+    /// Holds back [`winit::event::WindowEvent::Resized`] events and only delivers the most recent
+    /// one per window, right before [`crate::EventResumed::AboutToWait`].
+    ///
+    /// A pending resize is delivered early when a `ScaleFactorChanged` or `RedrawRequested` event
+    /// arrives for the same window, so the application always sees the size that belongs to the
+    /// new scale factor and never draws with a stale size. Pending resizes are also delivered
+    /// before the application is suspended or exits.
+    pub fn with_resize_coalescing(mut self, enabled: bool) -> Self {
+        self.options.coalesce_resizes = enabled;
+        self
+    }
+
+    /// Plugins are called in the order they were added.
+    pub fn with_plugin<P: ApplicationPlugin<TApplication, TUserEvent> + 'static>(
+        mut self,
+        plugin: P,
+    ) -> Self {
+        self.options.plugins.push(Box::new(plugin));
+        self
+    }
+
+    pub fn with_observer<O: ApplicationLifecycleObserver<TApplication, TUserEvent> + 'static>(
+        mut self,
+        observer: O,
+    ) -> Self {
+        self.options.observer = Some(Box::new(observer));
+        self
+    }
+
+    /// Reports every dispatched user event to `queue` so it can accept new events.
+    ///
+    /// The adapter cannot tell which sender an event came from, so `queue` and its clones must be
+    /// the only senders of user events. Events sent through an event loop proxy,
+    /// [`crate::AppEventSender`], `run_with_sender` or signal forwarding also free up a slot,
+    /// after which `capacity` no longer bounds the number of unprocessed events.
+    pub fn with_event_queue(mut self, queue: &BoundedEventQueue<TUserEvent>) -> Self {
+        self.options.event_queue = Some(queue.clone());
+        self
+    }
+
+    /// Terminates the process with exit code 1 when the application has not exited `timeout`
+    /// after an exit was requested. Relies on threads, so it is not available on the web.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_shutdown_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.options.shutdown_timeout = Some(timeout);
+        self
+    }
+
+    /// Limits how often the event loop wakes up to `max_fps` times per second. Applications that
+    /// poll or wait until an earlier time wait until the next frame is due instead. Panics if
+    /// `max_fps` is zero.
+    pub fn with_max_fps(mut self, max_fps: u32) -> Self {
+        assert!(max_fps > 0, "max_fps must be greater than zero");
+        self.options.frame_period =
+            Some(std::time::Duration::from_secs_f64(1.0 / f64::from(max_fps)));
+        self
+    }
+
+    /// Logs a warning whenever handling a single event, including the plugins, takes longer than
+    /// `budget`.
+    pub fn with_frame_budget(mut self, budget: std::time::Duration) -> Self {
+        self.options.frame_budget = Some(budget);
+        self
+    }
+
+    pub fn build(self, state: TApplication::Uninitialized) -> Adapter<TApplication, TUserEvent> {
+        Adapter::with_options(state, self.options)
+    }
+
+    pub fn run(
+        self,
+        event_loop: winit::event_loop::EventLoop<TUserEvent>,
+        state: TApplication::Uninitialized,
+    ) -> EventLoopResult<ApplicationResult<TApplication, TUserEvent>> {
+        let mut app = self.build(state);
+        event_loop.run_app(&mut app)?;
+        Ok(app.exit())
+    }
+
+    /// Starts the application without blocking, as required on the main thread of a browser.
+    ///
+    /// The exited state and any error are dropped when the event loop exits. Use
+    /// [`Self::with_error_handler`] to observe errors.
+    #[cfg(target_arch = "wasm32")]
+    pub fn spawn(
+        self,
+        event_loop: winit::event_loop::EventLoop<TUserEvent>,
+        state: TApplication::Uninitialized,
+    ) where
+        Adapter<TApplication, TUserEvent>: 'static,
+    {
+        winit::platform::web::EventLoopExtWebSys::spawn_app(event_loop, self.build(state))
+    }
+}
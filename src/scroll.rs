@@ -0,0 +1,45 @@
+use crate::EventResumed;
+
+/// Accumulates mouse wheel deltas until they are drained, typically once per frame.
+///
+/// Pixel and line deltas are kept apart because they are in different units.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScrollAccumulator {
+    pixel: (f64, f64),
+    line: (f64, f64),
+}
+
+impl ScrollAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update<TUserEvent>(&mut self, event: &EventResumed<TUserEvent>) {
+        if let EventResumed::WindowEvent {
+            event: winit::event::WindowEvent::MouseWheel { delta, .. },
+            ..
+        } = event
+        {
+            match *delta {
+                winit::event::MouseScrollDelta::LineDelta(x, y) => {
+                    self.line.0 += f64::from(x);
+                    self.line.1 += f64::from(y);
+                }
+                winit::event::MouseScrollDelta::PixelDelta(position) => {
+                    self.pixel.0 += position.x;
+                    self.pixel.1 += position.y;
+                }
+            }
+        }
+    }
+
+    /// Returns the accumulated pixel delta and resets it to zero.
+    pub fn drain_pixels(&mut self) -> (f64, f64) {
+        std::mem::take(&mut self.pixel)
+    }
+
+    /// Returns the accumulated line delta and resets it to zero.
+    pub fn drain_lines(&mut self) -> (f64, f64) {
+        std::mem::take(&mut self.line)
+    }
+}
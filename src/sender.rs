@@ -0,0 +1,54 @@
+/// Sends user events to the event loop when it is not important whether they arrive.
+pub struct AppEventSender<TUserEvent: 'static>(winit::event_loop::EventLoopProxy<TUserEvent>);
+
+impl<TUserEvent: 'static> Clone for AppEventSender<TUserEvent> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<TUserEvent: 'static> From<winit::event_loop::EventLoopProxy<TUserEvent>>
+    for AppEventSender<TUserEvent>
+{
+    fn from(proxy: winit::event_loop::EventLoopProxy<TUserEvent>) -> Self {
+        Self(proxy)
+    }
+}
+
+impl<TUserEvent: 'static> AppEventSender<TUserEvent> {
+    /// Returns `false` if the event loop has exited and the event was dropped.
+    pub fn send(&self, event: TUserEvent) -> bool {
+        self.0.send_event(event).is_ok()
+    }
+
+    pub fn send_or_log(&self, event: TUserEvent) {
+        self.0.send_or_log(event)
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl<T: 'static> Sealed for winit::event_loop::EventLoopProxy<T> {}
+}
+
+/// Sends user events without reporting whether the event loop is still running.
+pub trait EventLoopProxyExt<T>: private::Sealed {
+    /// Drops the event if the event loop has exited.
+    fn send_or_ignore(&self, event: T);
+
+    /// Logs a warning and drops the event if the event loop has exited.
+    fn send_or_log(&self, event: T);
+}
+
+impl<T: 'static> EventLoopProxyExt<T> for winit::event_loop::EventLoopProxy<T> {
+    fn send_or_ignore(&self, event: T) {
+        let _ = self.send_event(event);
+    }
+
+    fn send_or_log(&self, event: T) {
+        if self.send_event(event).is_err() {
+            log::warn!(target: "winit_ext", "dropped user event because the event loop has exited");
+        }
+    }
+}
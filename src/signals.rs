@@ -0,0 +1,55 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use signal_hook::consts::{SIGINT, SIGTERM};
+
+const SIGNALS: [std::ffi::c_int; 2] = [SIGINT, SIGTERM];
+
+/// Forwards SIGINT and SIGTERM to the event loop as user events until dropped.
+pub(crate) struct SignalForwarder {
+    handle: signal_hook::iterator::Handle,
+    /// Makes the signals terminate the process again once the forwarder is dropped, since
+    /// signal-hook never uninstalls its handlers.
+    terminate: Arc<AtomicBool>,
+}
+
+impl SignalForwarder {
+    pub fn install<TUserEvent: Clone + Send + 'static>(
+        proxy: winit::event_loop::EventLoopProxy<TUserEvent>,
+        shutdown_event: TUserEvent,
+    ) -> std::io::Result<Self> {
+        let terminate = Arc::new(AtomicBool::new(false));
+        let registered = SIGNALS.iter().try_for_each(|&signal| {
+            signal_hook::flag::register_conditional_default(signal, Arc::clone(&terminate))
+                .map(drop)
+        });
+        let mut signals =
+            match registered.and_then(|()| signal_hook::iterator::Signals::new(SIGNALS)) {
+                Ok(signals) => signals,
+                Err(error) => {
+                    // Keep the default behaviour for handlers that were already registered.
+                    terminate.store(true, Ordering::Relaxed);
+                    return Err(error);
+                }
+            };
+        let handle = signals.handle();
+        std::thread::spawn(move || {
+            // Ends when the handle is closed.
+            for _ in signals.forever() {
+                if proxy.send_event(shutdown_event.clone()).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(Self { handle, terminate })
+    }
+}
+
+impl Drop for SignalForwarder {
+    fn drop(&mut self) {
+        self.handle.close();
+        self.terminate.store(true, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::VecDeque;
+
+use crate::{Adapter, Application, ApplicationResumed};
+
+/// A resumed state that can be captured by [`SnapshotAdapter`].
+pub trait StateSnapshot<TUserEvent: 'static = ()>: ApplicationResumed<TUserEvent> + Clone {}
+
+impl<TUserEvent: 'static, T: ApplicationResumed<TUserEvent> + Clone> StateSnapshot<TUserEvent>
+    for T
+{
+}
+
+/// Wraps an [`Adapter`] and clones the resumed state after every
+/// [`crate::EventResumed::AboutToWait`], keeping the most recent snapshots up to a capacity.
+pub struct SnapshotAdapter<TApplication: Application<TUserEvent>, TUserEvent: 'static>
+where
+    TApplication::Resumed: StateSnapshot<TUserEvent>,
+{
+    adapter: Adapter<TApplication, TUserEvent>,
+    snapshots: VecDeque<TApplication::Resumed>,
+    capacity: usize,
+}
+
+impl<TApplication: Application<TUserEvent>, TUserEvent: 'static>
+    SnapshotAdapter<TApplication, TUserEvent>
+where
+    TApplication::Resumed: StateSnapshot<TUserEvent>,
+{
+    pub fn new(adapter: Adapter<TApplication, TUserEvent>, capacity: usize) -> Self {
+        Self {
+            adapter,
+            snapshots: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn latest_snapshot(&self) -> Option<&TApplication::Resumed> {
+        self.snapshots.back()
+    }
+
+    /// Returns the snapshots from oldest to newest.
+    pub fn snapshots(&self) -> impl Iterator<Item = &TApplication::Resumed> {
+        self.snapshots.iter()
+    }
+
+    pub fn into_parts(
+        self,
+    ) -> (
+        Adapter<TApplication, TUserEvent>,
+        VecDeque<TApplication::Resumed>,
+    ) {
+        (self.adapter, self.snapshots)
+    }
+
+    fn capture(&mut self) {
+        if self.capacity == 0 {
+            return;
+        }
+        if let Some(state) = self.adapter.resumed_state() {
+            if self.snapshots.len() == self.capacity {
+                self.snapshots.pop_front();
+            }
+            self.snapshots.push_back(state.clone());
+        }
+    }
+}
+
+impl<TApplication: Application<TUserEvent>, TUserEvent: 'static>
+    winit::application::ApplicationHandler<TUserEvent> for SnapshotAdapter<TApplication, TUserEvent>
+where
+    TApplication::Resumed: StateSnapshot<TUserEvent>,
+{
+    fn new_events(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        cause: winit::event::StartCause,
+    ) {
+        self.adapter.new_events(event_loop, cause)
+    }
+
+    fn user_event(&mut self, event_loop: &winit::event_loop::ActiveEventLoop, event: TUserEvent) {
+        self.adapter.user_event(event_loop, event)
+    }
+
+    fn device_event(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        device_id: winit::event::DeviceId,
+        event: winit::event::DeviceEvent,
+    ) {
+        self.adapter.device_event(event_loop, device_id, event)
+    }
+
+    fn about_to_wait(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.adapter.about_to_wait(event_loop);
+        self.capture()
+    }
+
+    fn suspended(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.adapter.suspended(event_loop)
+    }
+
+    fn exiting(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.adapter.exiting(event_loop)
+    }
+
+    fn memory_warning(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.adapter.memory_warning(event_loop)
+    }
+
+    fn resumed(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.adapter.resumed(event_loop)
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        window_id: winit::window::WindowId,
+        event: winit::event::WindowEvent,
+    ) {
+        self.adapter.window_event(event_loop, window_id, event)
+    }
+}
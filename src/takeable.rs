@@ -1,3 +1,4 @@
+#[derive(Debug)]
 pub(crate) struct Takeable<T>(Option<T>);
 
 impl<T> Takeable<T> {
@@ -5,11 +6,48 @@ impl<T> Takeable<T> {
         Self(Some(value))
     }
 
-    pub fn transition<F: FnOnce(T) -> T>(&mut self, f: F) {
-        self.0 = Some(f(self.0.take().unwrap_or_else(|| unreachable!())));
+    /// Replaces the value with `f(value)`. Stores `fallback()` before resuming the unwind when `f`
+    /// panics, so the value is never left empty.
+    pub fn transition_recovering<F: FnOnce(T) -> T, G: FnOnce() -> T>(
+        &mut self,
+        f: F,
+        fallback: G,
+    ) {
+        let value = self.0.take().unwrap_or_else(|| unreachable!());
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(value))) {
+            Ok(value) => self.0 = Some(value),
+            Err(payload) => {
+                self.0 = Some(fallback());
+                std::panic::resume_unwind(payload)
+            }
+        }
     }
 
-    pub fn get(self) -> T {
-        self.0.unwrap_or_else(|| unreachable!())
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Takeable<U> {
+        Takeable(self.0.map(f))
     }
+
+    pub fn as_ref(&self) -> Option<&T> {
+        self.0.as_ref()
+    }
+
+    pub fn as_mut(&mut self) -> Option<&mut T> {
+        self.0.as_mut()
+    }
+
+    pub fn peek(&self) -> &T {
+        self.as_ref().unwrap_or_else(|| lost())
+    }
+
+    pub fn peek_mut(&mut self) -> &mut T {
+        self.as_mut().unwrap_or_else(|| lost())
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0.unwrap_or_else(|| lost())
+    }
+}
+
+fn lost() -> ! {
+    panic!("value was lost because a transition panicked")
 }
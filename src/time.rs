@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use crate::Instant;
+
+/// The duration of a frame, as returned by [`crate::FrameTimer::tick`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DeltaTime(pub Duration);
+
+impl DeltaTime {
+    pub fn as_secs_f32(&self) -> f32 {
+        self.0.as_secs_f32()
+    }
+
+    pub fn as_secs_f64(&self) -> f64 {
+        self.0.as_secs_f64()
+    }
+
+    pub fn as_millis_f32(&self) -> f32 {
+        self.0.as_secs_f32() * 1000.0
+    }
+}
+
+impl From<Duration> for DeltaTime {
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
+
+impl From<DeltaTime> for Duration {
+    fn from(dt: DeltaTime) -> Self {
+        dt.0
+    }
+}
+
+/// The moment the application started. Create it once, typically in the uninitialized state, and
+/// carry it over into the resumed state so that every measurement shares the same origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AbsoluteTime(Instant);
+
+impl AbsoluteTime {
+    /// Starts measuring from now.
+    pub fn start() -> Self {
+        Self(Instant::now())
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.0.elapsed()
+    }
+
+    /// Returns the seconds elapsed since the start.
+    pub fn as_secs_f64(&self) -> f64 {
+        self.elapsed().as_secs_f64()
+    }
+}
@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+
+use crate::EventResumed;
+
+/// Tracks the position of every active touch point by its id.
+#[derive(Debug, Clone, Default)]
+pub struct TouchStateTracker(HashMap<u64, winit::dpi::PhysicalPosition<f64>>);
+
+impl TouchStateTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update<TUserEvent>(&mut self, event: &EventResumed<TUserEvent>) {
+        if let EventResumed::WindowEvent {
+            event: winit::event::WindowEvent::Touch(touch),
+            ..
+        } = event
+        {
+            match touch.phase {
+                winit::event::TouchPhase::Started | winit::event::TouchPhase::Moved => {
+                    self.0.insert(touch.id, touch.location);
+                }
+                winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
+                    self.0.remove(&touch.id);
+                }
+            }
+        }
+    }
+
+    pub fn active_touches(
+        &self,
+    ) -> impl Iterator<Item = (u64, winit::dpi::PhysicalPosition<f64>)> + '_ {
+        self.0.iter().map(|(&id, &position)| (id, position))
+    }
+}
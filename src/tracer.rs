@@ -0,0 +1,179 @@
+use crate::{
+    Application, ApplicationResumed, ApplicationStateKind, ApplicationSuspended,
+    ApplicationUninitialized, EventResumed, EventSuspended, InitialState,
+};
+
+/// Wraps an application and traces its state transitions and events.
+///
+/// With the `tracing` feature enabled, every state transition is emitted at the info level and
+/// every event passed to `handle` at the debug level. Without the feature the wrapper only
+/// forwards to the wrapped application.
+pub struct ApplicationTracer<T>(T);
+
+impl<T> ApplicationTracer<T> {
+    pub fn wrap(inner: T) -> Self {
+        Self(inner)
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<TUserEvent: 'static, TApplication: Application<TUserEvent>> Application<TUserEvent>
+    for ApplicationTracer<TApplication>
+{
+    type Uninitialized = ApplicationTracer<TApplication::Uninitialized>;
+    type Resumed = ApplicationTracer<TApplication::Resumed>;
+    type Suspended = ApplicationTracer<TApplication::Suspended>;
+    type Exited = TApplication::Exited;
+    type Error = TApplication::Error;
+}
+
+impl<TUserEvent: 'static, T: ApplicationUninitialized<TUserEvent>>
+    ApplicationUninitialized<TUserEvent> for ApplicationTracer<T>
+{
+    type Application = ApplicationTracer<T::Application>;
+
+    fn initialize(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        InitialState<Self::Application, TUserEvent>,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        Ok(match self.0.initialize(event_loop)? {
+            InitialState::Resumed(state) => {
+                trace_transition(
+                    std::any::type_name::<T::Application>(),
+                    ApplicationStateKind::Uninitialized,
+                    ApplicationStateKind::Resumed,
+                );
+                InitialState::Resumed(ApplicationTracer(state))
+            }
+            InitialState::Suspended(state) => {
+                trace_transition(
+                    std::any::type_name::<T::Application>(),
+                    ApplicationStateKind::Uninitialized,
+                    ApplicationStateKind::Suspended,
+                );
+                InitialState::Suspended(ApplicationTracer(state))
+            }
+        })
+    }
+}
+
+impl<TUserEvent: 'static, T: ApplicationResumed<TUserEvent>> ApplicationResumed<TUserEvent>
+    for ApplicationTracer<T>
+{
+    type Application = ApplicationTracer<T::Application>;
+
+    fn handle(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventResumed<TUserEvent>,
+    ) -> Result<Self, <Self::Application as Application<TUserEvent>>::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            application = std::any::type_name::<T::Application>(),
+            "handle {}",
+            crate::EventDescription(&event)
+        );
+        self.0.handle(event_loop, event).map(ApplicationTracer)
+    }
+
+    fn suspend(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Suspended,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        let state = self.0.suspend(event_loop)?;
+        trace_transition(
+            std::any::type_name::<T::Application>(),
+            ApplicationStateKind::Resumed,
+            ApplicationStateKind::Suspended,
+        );
+        Ok(ApplicationTracer(state))
+    }
+
+    fn exit(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Exited,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        let exited = self.0.exit(event_loop)?;
+        trace_transition(
+            std::any::type_name::<T::Application>(),
+            ApplicationStateKind::Resumed,
+            ApplicationStateKind::Exited,
+        );
+        Ok(exited)
+    }
+}
+
+impl<TUserEvent: 'static, T: ApplicationSuspended<TUserEvent>> ApplicationSuspended<TUserEvent>
+    for ApplicationTracer<T>
+{
+    type Application = ApplicationTracer<T::Application>;
+
+    fn handle(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventSuspended<TUserEvent>,
+    ) -> Result<Self, <Self::Application as Application<TUserEvent>>::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            application = std::any::type_name::<T::Application>(),
+            "handle {}",
+            crate::EventDescription(&event)
+        );
+        self.0.handle(event_loop, event).map(ApplicationTracer)
+    }
+
+    fn resume(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Resumed,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        let state = self.0.resume(event_loop)?;
+        trace_transition(
+            std::any::type_name::<T::Application>(),
+            ApplicationStateKind::Suspended,
+            ApplicationStateKind::Resumed,
+        );
+        Ok(ApplicationTracer(state))
+    }
+
+    fn exit(
+        self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+    ) -> Result<
+        <Self::Application as Application<TUserEvent>>::Exited,
+        <Self::Application as Application<TUserEvent>>::Error,
+    > {
+        let exited = self.0.exit(event_loop)?;
+        trace_transition(
+            std::any::type_name::<T::Application>(),
+            ApplicationStateKind::Suspended,
+            ApplicationStateKind::Exited,
+        );
+        Ok(exited)
+    }
+}
+
+fn trace_transition(
+    application: &'static str,
+    from: ApplicationStateKind,
+    to: ApplicationStateKind,
+) {
+    #[cfg(feature = "tracing")]
+    tracing::info!(application, "{from:?} -> {to:?}");
+    #[cfg(not(feature = "tracing"))]
+    let _ = (application, from, to);
+}
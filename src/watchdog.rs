@@ -0,0 +1,35 @@
+use std::sync::{Arc, Condvar, Mutex};
+
+/// Terminates the process unless it is dropped within the timeout.
+pub(crate) struct ShutdownWatchdog {
+    done: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl ShutdownWatchdog {
+    pub fn start(timeout: std::time::Duration) -> Self {
+        let done = Arc::new((Mutex::new(false), Condvar::new()));
+        std::thread::spawn({
+            let done = done.clone();
+            move || {
+                let (lock, condvar) = &*done;
+                let guard = lock.lock().unwrap_or_else(|error| error.into_inner());
+                let (guard, _) = condvar
+                    .wait_timeout_while(guard, timeout, |done| !*done)
+                    .unwrap_or_else(|error| error.into_inner());
+                if !*guard {
+                    log::warn!(target: "winit_ext", "application did not exit within {timeout:?}, terminating");
+                    std::process::exit(1)
+                }
+            }
+        });
+        Self { done }
+    }
+}
+
+impl Drop for ShutdownWatchdog {
+    fn drop(&mut self) {
+        let (lock, condvar) = &*self.done;
+        *lock.lock().unwrap_or_else(|error| error.into_inner()) = true;
+        condvar.notify_one();
+    }
+}
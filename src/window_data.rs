@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+/// Associates a value with each window, for example its renderer or UI state.
+#[derive(Debug, Clone)]
+pub struct WindowDataMap<V>(HashMap<winit::window::WindowId, V>);
+
+impl<V> Default for WindowDataMap<V> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<V> WindowDataMap<V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, id: winit::window::WindowId) -> Option<&V> {
+        self.0.get(&id)
+    }
+
+    pub fn get_mut(&mut self, id: winit::window::WindowId) -> Option<&mut V> {
+        self.0.get_mut(&id)
+    }
+
+    pub fn insert(&mut self, id: winit::window::WindowId, value: V) -> Option<V> {
+        self.0.insert(id, value)
+    }
+
+    pub fn remove(&mut self, id: winit::window::WindowId) -> Option<V> {
+        self.0.remove(&id)
+    }
+
+    pub fn get_or_insert_with(
+        &mut self,
+        id: winit::window::WindowId,
+        f: impl FnOnce() -> V,
+    ) -> &mut V {
+        self.0.entry(id).or_insert_with(f)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<V> IntoIterator for WindowDataMap<V> {
+    type Item = (winit::window::WindowId, V);
+    type IntoIter = std::collections::hash_map::IntoIter<winit::window::WindowId, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
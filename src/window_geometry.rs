@@ -0,0 +1,74 @@
+/// The outer position and inner size of a window, for restoring it where the user left it.
+///
+/// With the `serde` feature it serializes as a flat object with the fields `x`, `y`, `width` and
+/// `height`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "FlatWindowGeometry", into = "FlatWindowGeometry")
+)]
+pub struct WindowGeometry {
+    pub position: winit::dpi::PhysicalPosition<i32>,
+    pub size: winit::dpi::PhysicalSize<u32>,
+}
+
+impl WindowGeometry {
+    /// Uses the origin as the position on platforms that do not report it.
+    pub fn capture(window: &winit::window::Window) -> Self {
+        Self {
+            position: window.outer_position().unwrap_or_default(),
+            size: window.inner_size(),
+        }
+    }
+
+    pub fn restore(&self, window: &winit::window::Window) {
+        let _ = window.request_inner_size(self.size);
+        window.set_outer_position(self.position);
+    }
+
+    /// Writes the geometry to `path` as JSON.
+    #[cfg(feature = "serde")]
+    pub fn save_to_file(&self, path: &std::path::Path) -> std::io::Result<()> {
+        std::fs::write(path, serde_json::to_vec(self)?)
+    }
+
+    /// Reads a geometry written by [`Self::save_to_file`].
+    #[cfg(feature = "serde")]
+    pub fn load_from_file(path: &std::path::Path) -> std::io::Result<Self> {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+}
+
+/// The serialized form of [`WindowGeometry`], because winit only implements serde for its
+/// geometry types behind its own `serde` feature.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct FlatWindowGeometry {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+}
+
+#[cfg(feature = "serde")]
+impl From<FlatWindowGeometry> for WindowGeometry {
+    fn from(geometry: FlatWindowGeometry) -> Self {
+        Self {
+            position: winit::dpi::PhysicalPosition::new(geometry.x, geometry.y),
+            size: winit::dpi::PhysicalSize::new(geometry.width, geometry.height),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<WindowGeometry> for FlatWindowGeometry {
+    fn from(geometry: WindowGeometry) -> Self {
+        Self {
+            x: geometry.position.x,
+            y: geometry.position.y,
+            width: geometry.size.width,
+            height: geometry.size.height,
+        }
+    }
+}
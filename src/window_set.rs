@@ -0,0 +1,57 @@
+use std::{collections::HashMap, sync::Arc};
+
+/// Keeps track of the open windows of a multi-window application by their id.
+#[derive(Debug, Default)]
+pub struct WindowSet {
+    windows: HashMap<winit::window::WindowId, Arc<winit::window::Window>>,
+}
+
+impl WindowSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a window and adds it to the set.
+    pub fn create(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        attributes: winit::window::WindowAttributes,
+    ) -> Result<Arc<winit::window::Window>, winit::error::OsError> {
+        let window = Arc::new(event_loop.create_window(attributes)?);
+        self.register(window.clone());
+        Ok(window)
+    }
+
+    /// Adds a window that was created elsewhere.
+    pub fn register(&mut self, window: Arc<winit::window::Window>) {
+        self.windows.insert(window.id(), window);
+    }
+
+    pub fn remove(&mut self, id: winit::window::WindowId) -> Option<Arc<winit::window::Window>> {
+        self.windows.remove(&id)
+    }
+
+    pub fn get(&self, id: winit::window::WindowId) -> Option<&Arc<winit::window::Window>> {
+        self.windows.get(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (winit::window::WindowId, &Arc<winit::window::Window>)> {
+        self.windows.iter().map(|(&id, window)| (id, window))
+    }
+
+    /// Removes all windows. A window closes when its last [`Arc`] is dropped, so windows that
+    /// are still referenced elsewhere stay open.
+    pub fn close_all(&mut self) {
+        self.windows.clear()
+    }
+}
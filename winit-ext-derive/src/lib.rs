@@ -0,0 +1,105 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Implements `winit_ext::Application` from an `#[application(...)]` attribute listing the state
+/// types.
+///
+/// The `uninitialized`, `resumed`, `suspended`, `exited` and `error` keys are required. The
+/// implementation is generic over the user event type unless `user_event` is given.
+#[proc_macro_derive(Application, attributes(application))]
+pub fn derive_application(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Default)]
+struct Attributes {
+    uninitialized: Option<syn::Type>,
+    resumed: Option<syn::Type>,
+    suspended: Option<syn::Type>,
+    exited: Option<syn::Type>,
+    error: Option<syn::Type>,
+    user_event: Option<syn::Type>,
+}
+
+fn parse_attributes(input: &syn::DeriveInput) -> syn::Result<Attributes> {
+    let mut attributes = Attributes::default();
+    for attr in &input.attrs {
+        if !attr.path().is_ident("application") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            let slot = if meta.path.is_ident("uninitialized") {
+                &mut attributes.uninitialized
+            } else if meta.path.is_ident("resumed") {
+                &mut attributes.resumed
+            } else if meta.path.is_ident("suspended") {
+                &mut attributes.suspended
+            } else if meta.path.is_ident("exited") {
+                &mut attributes.exited
+            } else if meta.path.is_ident("error") {
+                &mut attributes.error
+            } else if meta.path.is_ident("user_event") {
+                &mut attributes.user_event
+            } else {
+                return Err(meta.error("unknown application attribute"));
+            };
+            if slot.is_some() {
+                return Err(meta.error("duplicate application attribute"));
+            }
+            *slot = Some(meta.value()?.parse()?);
+            Ok(())
+        })?;
+    }
+    Ok(attributes)
+}
+
+fn expand(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let Attributes {
+        uninitialized,
+        resumed,
+        suspended,
+        exited,
+        error,
+        user_event,
+    } = parse_attributes(&input)?;
+    let required = |value: Option<syn::Type>, key: &str| {
+        value.ok_or_else(|| {
+            syn::Error::new_spanned(
+                &input.ident,
+                format!("missing `#[application({key} = ...)]` attribute"),
+            )
+        })
+    };
+    let uninitialized = required(uninitialized, "uninitialized")?;
+    let resumed = required(resumed, "resumed")?;
+    let suspended = required(suspended, "suspended")?;
+    let exited = required(exited, "exited")?;
+    let error = required(error, "error")?;
+
+    let ident = &input.ident;
+    let (_, type_generics, _) = input.generics.split_for_impl();
+    let mut generics = input.generics.clone();
+    let user_event = match user_event {
+        Some(user_event) => quote!(#user_event),
+        None => {
+            generics.params.push(syn::parse_quote!(TUserEvent: 'static));
+            quote!(TUserEvent)
+        }
+    };
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::winit_ext::Application<#user_event> for #ident #type_generics
+        #where_clause
+        {
+            type Uninitialized = #uninitialized;
+            type Resumed = #resumed;
+            type Suspended = #suspended;
+            type Exited = #exited;
+            type Error = #error;
+        }
+    })
+}